@@ -16,7 +16,9 @@ async fn main() {
         secret: "".to_owned(),  // Testnet keys
     };
     let client = Client::new_testnet(credentials.clone(), None);
-    let client_ws = ClientWS::new_testnet(credentials);
+    let offset = client.sync_clock().await;
+    println!("Clock offset: {:?}", offset);
+    let client_ws = ClientWS::new_testnet(credentials).with_clock(client.clock());
 
     let (sender, mut receiver) = client_ws.connect().await.unwrap();
     sender
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::rest::model::{ResponseMeta, RetExtInfo};
+use crate::RetCode;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be built or sent, or the response could not be read.
+    Transport(reqwest::Error),
+    /// The request timed out.
+    Timeout(reqwest::Error),
+    /// The server answered with a non-success HTTP status.
+    Status {
+        status: StatusCode,
+        body: String,
+        meta: Box<ResponseMeta>,
+    },
+    /// The response body, or a field of it, is not what was expected.
+    Decode {
+        source: serde_json::Error,
+        body: String,
+        /// Empty if a field of an already decoded response is invalid.
+        meta: Box<ResponseMeta>,
+    },
+    /// Bybit rejected the request with a nonzero `retCode`.
+    Api {
+        ret_code: u64,
+        ret_msg: String,
+        // Box to keep `Result<_, Error>` small
+        ret_ext_info: Box<RetExtInfo>,
+        meta: Box<ResponseMeta>,
+    },
+    /// The request succeeded but the response carries no result.
+    MissingResult,
+    /// The client-side rate limiter rejected the request.
+    RateLimited {
+        group: String,
+        retry_after: Duration,
+    },
+    /// The convert quote expired before it could be confirmed.
+    QuoteExpired {
+        quote_tx_id: String,
+        expired_time: u64,
+    },
+}
+
+impl Error {
+    /// An `Error::Decode` for a field `value` of a decoded response.
+    pub(crate) fn invalid(msg: &str, value: &str) -> Self {
+        Error::Decode {
+            source: serde::de::Error::custom(msg),
+            body: value.to_string(),
+            meta: Box::default(),
+        }
+    }
+
+    /// The Bybit `retCode` of an `Error::Api`.
+    pub fn ret_code(&self) -> Option<RetCode> {
+        match self {
+            Error::Api { ret_code, .. } => Some(RetCode::from(*ret_code)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+            Error::Timeout(e) => write!(f, "timeout: {}", e),
+            Error::Status { status, body, .. } => write!(f, "http status {}: {}", status, body),
+            Error::Decode { source, body, .. } => write!(f, "decode error: {} in {}", source, body),
+            Error::Api {
+                ret_code, ret_msg, ..
+            } => write!(f, "api error {}: {}", ret_code, ret_msg),
+            Error::MissingResult => write!(f, "missing result"),
+            Error::RateLimited { group, retry_after } => {
+                write!(
+                    f,
+                    "rate limit of {} exceeded, retry after {:?}",
+                    group, retry_after
+                )
+            }
+            Error::QuoteExpired {
+                quote_tx_id,
+                expired_time,
+            } => write!(f, "quote {} expired at {}", quote_tx_id, expired_time),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) | Error::Timeout(e) => Some(e),
+            Error::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Error::Timeout(e)
+        } else {
+            Error::Transport(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::model::{CancelOrderResponse, Response};
+
+    #[test]
+    fn test_into_result() {
+        let res: Response<CancelOrderResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"1","orderLinkId":"a"},"retExtInfo":{},"time":1}"#,
+        )
+        .unwrap();
+        assert_eq!(res.into_result().unwrap().order_id, "1");
+
+        let res: Response<CancelOrderResponse> = serde_json::from_str(
+            r#"{"retCode":110001,"retMsg":"order not exists or too late to cancel","result":{},"retExtInfo":{},"time":1}"#,
+        )
+        .unwrap();
+        match res.into_result() {
+            Err(e @ Error::Api { .. }) => assert!(e.ret_code().unwrap().is_order_not_found()),
+            Err(e) => panic!("unexpected {:?}", e),
+            Ok(v) => panic!("unexpected {:?}", v),
+        }
+    }
+}
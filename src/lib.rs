@@ -1,7 +1,12 @@
+pub mod error;
 pub mod rest;
+pub mod ret_code;
 pub mod util;
 pub mod ws;
 
+pub use error::Error;
+pub use ret_code::RetCode;
+
 #[derive(Clone)]
 pub struct Credentials {
     pub api_key: String,
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::model::ResponseMeta;
+use crate::util::millis;
+use crate::Error;
+
+/// What to do with a request over the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnLimit {
+    /// Wait until the window resets.
+    Wait,
+    /// Fail with `Error::RateLimited`.
+    Reject,
+}
+
+pub const IP_GROUP: &str = "ip";
+const IP_LIMIT: u64 = 600;
+const IP_PERIOD: Duration = Duration::from_secs(5);
+const UID_PERIOD: Duration = Duration::from_secs(1);
+
+/// The rate limit group and its per second limit of an endpoint.
+///
+/// Order endpoints are limited per category, the other groups per endpoint.
+/// Endpoints which are not listed are only limited by the IP-wide limit.
+pub fn group(endpoint: &str, category: Option<&str>) -> Option<(String, u64)> {
+    let limit = match endpoint {
+        "/v5/order/create"
+        | "/v5/order/amend"
+        | "/v5/order/cancel"
+        | "/v5/order/cancel-all"
+        | "/v5/order/create-batch"
+        | "/v5/order/amend-batch"
+        | "/v5/order/cancel-batch" => match category {
+            Some("spot") => 20,
+            _ => 10,
+        },
+        "/v5/order/realtime"
+        | "/v5/order/history"
+        | "/v5/execution/list"
+        | "/v5/position/list"
+        | "/v5/position/closed-pnl"
+        | "/v5/account/wallet-balance"
+        | "/v5/account/fee-rate"
+        | "/v5/account/transaction-log" => 50,
+        "/v5/position/set-leverage"
+        | "/v5/position/switch-isolated"
+        | "/v5/position/switch-mode"
+        | "/v5/position/set-tpsl-mode"
+        | "/v5/position/trading-stop" => 10,
+        _ => return None,
+    };
+    let group = match category {
+        Some(category) => format!("{}:{}", endpoint, category),
+        None => endpoint.to_string(),
+    };
+    Some((group, limit))
+}
+
+#[derive(Debug)]
+struct Window {
+    limit: u64,
+    period: Duration,
+    remaining: u64,
+    reset_at: Instant,
+}
+
+impl Window {
+    fn new(limit: u64, period: Duration) -> Self {
+        Window {
+            limit,
+            period,
+            remaining: limit,
+            reset_at: Instant::now() + period,
+        }
+    }
+
+    /// Takes a request from the window, or returns how long to wait for the next one.
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        if now >= self.reset_at {
+            self.remaining = self.limit;
+            self.reset_at = now + self.period;
+        }
+        if self.remaining == 0 {
+            return Err(self.reset_at - now);
+        }
+        self.remaining -= 1;
+        Ok(())
+    }
+}
+
+/// Client-side rate limiter for Bybit's IP-wide and per UID endpoint limits.
+///
+/// The per endpoint windows calibrate themselves from the `X-Bapi-Limit*` headers.
+#[derive(Debug)]
+pub struct RateLimiter {
+    on_limit: OnLimit,
+    windows: Mutex<HashMap<String, Window>>,
+}
+
+impl RateLimiter {
+    pub fn new(on_limit: OnLimit) -> Self {
+        let mut windows = HashMap::new();
+        windows.insert(IP_GROUP.to_string(), Window::new(IP_LIMIT, IP_PERIOD));
+        RateLimiter {
+            on_limit,
+            windows: Mutex::new(windows),
+        }
+    }
+
+    /// Waits for (or rejects) a request of `group` and the IP-wide group.
+    pub async fn acquire(&self, group: Option<&(String, u64)>) -> Result<(), Error> {
+        loop {
+            let wait = self.try_acquire(group);
+            let (group, wait) = match wait {
+                Ok(()) => return Ok(()),
+                Err(wait) => wait,
+            };
+            match self.on_limit {
+                OnLimit::Wait => tokio::time::sleep(wait).await,
+                OnLimit::Reject => {
+                    return Err(Error::RateLimited {
+                        group,
+                        retry_after: wait,
+                    })
+                }
+            }
+        }
+    }
+
+    fn try_acquire(&self, group: Option<&(String, u64)>) -> Result<(), (String, Duration)> {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap();
+        // Check both windows before taking from either, so a rejected request is not counted
+        for name in [Some(IP_GROUP), group.map(|g| g.0.as_str())]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(window) = windows.get(name) {
+                if now < window.reset_at && window.remaining == 0 {
+                    return Err((name.to_string(), window.reset_at - now));
+                }
+            }
+        }
+        if let Some((name, limit)) = group {
+            let window = windows
+                .entry(name.clone())
+                .or_insert_with(|| Window::new(*limit, UID_PERIOD));
+            window.take(now).map_err(|wait| (name.clone(), wait))?;
+        }
+        let ip = windows.get_mut(IP_GROUP).unwrap();
+        ip.take(now).map_err(|wait| (IP_GROUP.to_string(), wait))
+    }
+
+    /// Calibrates the window of `group` from the headers of its last response.
+    pub fn update(&self, group: &str, meta: &ResponseMeta) {
+        let mut windows = self.windows.lock().unwrap();
+        let window = match windows.get_mut(group) {
+            Some(window) => window,
+            None => return,
+        };
+        if let Some(limit) = meta.limit {
+            window.limit = limit;
+        }
+        let now = Instant::now();
+        let reset_at = meta.limit_reset_timestamp.map(|reset| {
+            let server_now = meta.time_now.unwrap_or_else(millis);
+            now + Duration::from_millis(reset.saturating_sub(server_now))
+        });
+        // A late response of the current window must not give back requests
+        // which were sent after it, so only a new window resets the count
+        match reset_at {
+            Some(reset_at)
+                if now >= window.reset_at || reset_at >= window.reset_at + window.period / 2 =>
+            {
+                window.reset_at = reset_at;
+                if let Some(remaining) = meta.limit_status {
+                    window.remaining = remaining;
+                }
+            }
+            _ => {
+                if let Some(remaining) = meta.limit_status {
+                    window.remaining = window.remaining.min(remaining);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reject() {
+        let limiter = RateLimiter::new(OnLimit::Reject);
+        let group = group("/v5/order/create", Some("linear"));
+        assert_eq!(group, Some(("/v5/order/create:linear".to_string(), 10)));
+        for _ in 0..10 {
+            limiter.acquire(group.as_ref()).await.unwrap();
+        }
+        match limiter.acquire(group.as_ref()).await {
+            Err(Error::RateLimited { group, .. }) => assert_eq!(group, "/v5/order/create:linear"),
+            other => panic!("unexpected {:?}", other),
+        }
+        // Other groups only share the IP-wide limit
+        limiter
+            .acquire(super::group("/v5/order/create", Some("spot")).as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update() {
+        let limiter = RateLimiter::new(OnLimit::Reject);
+        let group = group("/v5/order/realtime", None);
+        limiter.acquire(group.as_ref()).await.unwrap();
+        limiter.update(
+            "/v5/order/realtime",
+            &ResponseMeta {
+                limit: Some(50),
+                limit_status: Some(0),
+                limit_reset_timestamp: Some(millis() + 1_000),
+                trace_id: None,
+                time_now: None,
+            },
+        );
+        assert!(limiter.acquire(group.as_ref()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_late_response() {
+        let limiter = RateLimiter::new(OnLimit::Reject);
+        let group = group("/v5/order/create", Some("linear"));
+        for _ in 0..10 {
+            limiter.acquire(group.as_ref()).await.unwrap();
+        }
+        // The response to the first request of the window arrives last
+        let meta = |reset| ResponseMeta {
+            limit: Some(10),
+            limit_status: Some(9),
+            limit_reset_timestamp: Some(reset),
+            trace_id: None,
+            time_now: None,
+        };
+        limiter.update("/v5/order/create:linear", &meta(millis() + 1_000));
+        assert!(limiter.acquire(group.as_ref()).await.is_err());
+
+        // A response of the next window resets the count
+        limiter.update("/v5/order/create:linear", &meta(millis() + 2_000));
+        limiter.acquire(group.as_ref()).await.unwrap();
+    }
+}
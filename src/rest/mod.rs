@@ -1,33 +1,73 @@
+pub mod limiter;
 pub mod model;
+pub mod retry;
 
 pub const MAINNET_URL: &str = "https://api.bybit.com";
 pub const TESTNET_URL: &str = "https://api-testnet.bybit.com";
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::Method;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    util::{millis, sign},
-    Credentials,
+    util::{clock_offset, millis, sign, Clock},
+    Credentials, Error,
 };
 
+use self::limiter::RateLimiter;
 use self::model::{
-    CancelAllOrderRequest, CancelOrderRequest, InstrumentsInfoRequest, PlaceOrderRequest,
+    AccountInfoResponse, AllCoinsBalanceResponse, AmendOrderResponse, ApiKeyInfo,
+    BatchOrderResponse, BorrowCheckResponse, BorrowHistoryResponse, CancelAllOrderResponse,
+    CancelOrderResponse, CancelWithdrawalResponse, Category, ClosedPnlResponse, CoinGreeksResponse,
+    CoinInfoResponse, CollateralInfoResponse, ConvertCoinsResponse, ConvertExecuteResponse,
+    ConvertQuote, ConvertResultResponse, DeliveryPriceResponse, DeliveryRecordResponse,
+    DepositAddressResponse, DepositRecordsResponse, EmptyResponse, ExecutionsResponse,
+    FeeRateResponse, FundingRateHistoryResponse, HistoricalVolatilityResponse,
+    InstrumentsInfoResponse, InsuranceResponse, InternalTransferRecordsResponse,
+    InternalTransferResponse, KlineResponse, LeveragedTokenInfoResponse, LeveragedTokenMarket,
+    LeveragedTokenOrdersResponse, LongShortRatioResponse, MmpStateResponse, OpenInterestResponse,
+    Orderbook, OrdersResponse, PlaceOrderResponse, PositionsResponse, PriceKlineResponse,
+    PurchaseLeveragedTokenResponse, RecentTradesResponse, RedeemLeveragedTokenResponse, Response,
+    ResponseMeta, RiskLimitResponse, ServerTimeResponse, SetTpslModeResponse,
+    SettlementRecordResponse, SingleCoinBalanceResponse, SpotMarginDataResponse,
+    SpotMarginModeResponse, SpotMarginStateResponse, SubApiKeyResponse, SubMember,
+    SubMembersResponse, TickersResponse, TransactionLogResponse, TransferableCoinsResponse,
+    WalletBalanceResponse, WithdrawResponse, WithdrawalRecordsResponse,
 };
 use self::model::{
-    CancelAllOrderResponse, CancelOrderResponse, InstrumentsInfoResponse, PlaceOrderResponse,
-    Response,
+    AllCoinsBalanceRequest, AmendOrderRequest, BatchAmendOrderRequest, BatchCancelOrderRequest,
+    BatchPlaceOrderRequest, BorrowCheckRequest, BorrowHistoryRequest, CancelAllOrderRequest,
+    CancelOrderRequest, CancelWithdrawalRequest, ClosedPnlRequest, CoinGreeksRequest,
+    CoinInfoRequest, CollateralInfoRequest, CollateralSwitchRequest, ConvertCoinsRequest,
+    ConvertExecuteRequest, ConvertQuoteRequest, ConvertResultRequest, CreateSubApiKeyRequest,
+    CreateSubMemberRequest, DeleteSubApiKeyRequest, DeliveryPriceRequest, DeliveryRecordRequest,
+    DepositAddressRequest, DepositRecordsRequest, ExecutionsRequest, FeeRateRequest,
+    FreezeSubMemberRequest, FundingRateHistoryRequest, HistoricalVolatilityRequest,
+    InstrumentsInfoRequest, InsuranceRequest, InternalTransferRecordsRequest,
+    InternalTransferRequest, KlineRequest, LeveragedTokenOrdersRequest, LeveragedTokenRequest,
+    LongShortRatioRequest, MmpRequest, ModifySubApiKeyRequest, OpenInterestRequest,
+    OpenOrdersRequest, OrderHistoryRequest, OrderbookRequest, PlaceOrderRequest, PositionsRequest,
+    PurchaseLeveragedTokenRequest, RecentTradesRequest, RedeemLeveragedTokenRequest,
+    RiskLimitRequest, SetDcpRequest, SetLeverageRequest, SetMmpRequest, SetTpslModeRequest,
+    SettlementRecordRequest, SingleCoinBalanceRequest, SpotMarginDataRequest,
+    SpotMarginLeverageRequest, SpotMarginModeRequest, SwitchIsolatedRequest,
+    SwitchPositionModeRequest, TickersRequest, TradingStopRequest, TransactionLogRequest,
+    TransferableCoinsRequest, WalletBalanceRequest, WithdrawRequest, WithdrawalRecordsRequest,
 };
+use self::retry::{is_idempotent, RetryPolicy};
 
 #[macro_export]
 macro_rules! handle {
+    ($name:ident, $endpoint:expr, $method:ident, $response:ident) => {
+        pub async fn $name(&self) -> Result<Response<$response>, $crate::Error> {
+            self.request::<(), _>($endpoint.to_string(), Method::$method, None)
+                .await
+        }
+    };
     ($name:ident, $endpoint:expr, $method:ident, $params:expr, $request:ident, $response:ident) => {
-        pub async fn $name(
-            &self,
-            request: $request,
-        ) -> Result<Response<$response>, reqwest::Error> {
+        pub async fn $name(&self, request: $request) -> Result<Response<$response>, $crate::Error> {
             self.request(
                 $endpoint.to_string(),
                 Method::$method,
@@ -40,12 +80,18 @@ macro_rules! handle {
 
 #[macro_export]
 macro_rules! handle_sig {
+    ($name:ident, $endpoint:expr, $method:ident, $response:ident) => {
+        pub async fn $name(&self, recv_window: u64) -> Result<Response<$response>, $crate::Error> {
+            self.request_signed::<(), _>($endpoint.to_string(), Method::$method, recv_window, None)
+                .await
+        }
+    };
     ($name:ident, $endpoint:expr, $method:ident, $params:expr, $request:ident, $response:ident) => {
         pub async fn $name(
             &self,
             request: $request,
             recv_window: u64,
-        ) -> Result<Response<$response>, reqwest::Error> {
+        ) -> Result<Response<$response>, $crate::Error> {
             self.request_signed(
                 $endpoint.to_string(),
                 Method::$method,
@@ -67,6 +113,9 @@ pub struct Client {
     credentials: Credentials,
     inner: reqwest::Client,
     host: &'static str,
+    clock: Clock,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl Client {
@@ -79,6 +128,9 @@ impl Client {
             credentials,
             inner: builder.build().unwrap(),
             host: MAINNET_URL,
+            clock: Clock::new(),
+            rate_limiter: None,
+            retry_policy: None,
         }
     }
 
@@ -91,9 +143,63 @@ impl Client {
             credentials,
             inner: builder.build().unwrap(),
             host: TESTNET_URL,
+            clock: Clock::new(),
+            rate_limiter: None,
+            retry_policy: None,
         }
     }
 
+    /// Uses `clock` to timestamp signed requests, e.g. to share it with a websocket client.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Paces requests through `rate_limiter`; clones of the client share it.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// Retries GET requests and order placement with an `order_link_id` under `retry_policy`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Measures the offset to the server clock and applies it to signed requests.
+    pub async fn sync_clock(&self) -> Result<i64, Error> {
+        let endpoint = "/v5/market/time";
+        // Not retried, and timed after the rate limiter, so that neither backoff
+        // nor waiting for the limiter counts as round trip
+        let group = self.acquire(endpoint, None).await?;
+        let request = self
+            .inner
+            .request(Method::GET, format!("{}{}", self.host, endpoint))
+            .build()?;
+        let sent = millis();
+        let res = self.send(request, group).await;
+        let received = millis();
+        apply_server_time(&self.clock, sent, received, res?)
+    }
+
+    /// Spawns a task which re-syncs the clock every `interval`.
+    pub fn spawn_clock_sync(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = client.sync_clock().await {
+                    eprintln!("Error: {}", e);
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
+    }
+
     handle_sig!(
         place_order,
         "/v5/order/create",
@@ -102,6 +208,14 @@ impl Client {
         PlaceOrderRequest,
         PlaceOrderResponse
     );
+    handle_sig!(
+        amend_order,
+        "/v5/order/amend",
+        POST,
+        Params::Body,
+        AmendOrderRequest,
+        AmendOrderResponse
+    );
     handle_sig!(
         cancel_order,
         "/v5/order/cancel",
@@ -118,6 +232,465 @@ impl Client {
         CancelAllOrderRequest,
         CancelAllOrderResponse
     );
+    handle_sig!(
+        batch_place_order,
+        "/v5/order/create-batch",
+        POST,
+        Params::Body,
+        BatchPlaceOrderRequest,
+        BatchOrderResponse
+    );
+    handle_sig!(
+        batch_amend_order,
+        "/v5/order/amend-batch",
+        POST,
+        Params::Body,
+        BatchAmendOrderRequest,
+        BatchOrderResponse
+    );
+    handle_sig!(
+        batch_cancel_order,
+        "/v5/order/cancel-batch",
+        POST,
+        Params::Body,
+        BatchCancelOrderRequest,
+        BatchOrderResponse
+    );
+    handle_sig!(
+        set_dcp,
+        "/v5/order/disconnected-cancel-all",
+        POST,
+        Params::Body,
+        SetDcpRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        get_open_orders,
+        "/v5/order/realtime",
+        GET,
+        Params::Query,
+        OpenOrdersRequest,
+        OrdersResponse
+    );
+    handle_sig!(
+        get_order_history,
+        "/v5/order/history",
+        GET,
+        Params::Query,
+        OrderHistoryRequest,
+        OrdersResponse
+    );
+    handle_sig!(
+        get_executions,
+        "/v5/execution/list",
+        GET,
+        Params::Query,
+        ExecutionsRequest,
+        ExecutionsResponse
+    );
+    handle_sig!(
+        get_positions,
+        "/v5/position/list",
+        GET,
+        Params::Query,
+        PositionsRequest,
+        PositionsResponse
+    );
+    handle_sig!(
+        set_leverage,
+        "/v5/position/set-leverage",
+        POST,
+        Params::Body,
+        SetLeverageRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        switch_isolated,
+        "/v5/position/switch-isolated",
+        POST,
+        Params::Body,
+        SwitchIsolatedRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        switch_position_mode,
+        "/v5/position/switch-mode",
+        POST,
+        Params::Body,
+        SwitchPositionModeRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        set_tpsl_mode,
+        "/v5/position/set-tpsl-mode",
+        POST,
+        Params::Body,
+        SetTpslModeRequest,
+        SetTpslModeResponse
+    );
+    handle_sig!(
+        set_trading_stop,
+        "/v5/position/trading-stop",
+        POST,
+        Params::Body,
+        TradingStopRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        get_closed_pnl,
+        "/v5/position/closed-pnl",
+        GET,
+        Params::Query,
+        ClosedPnlRequest,
+        ClosedPnlResponse
+    );
+    handle_sig!(
+        get_wallet_balance,
+        "/v5/account/wallet-balance",
+        GET,
+        Params::Query,
+        WalletBalanceRequest,
+        WalletBalanceResponse
+    );
+    handle_sig!(
+        get_account_info,
+        "/v5/account/info",
+        GET,
+        AccountInfoResponse
+    );
+    handle_sig!(
+        get_fee_rate,
+        "/v5/account/fee-rate",
+        GET,
+        Params::Query,
+        FeeRateRequest,
+        FeeRateResponse
+    );
+    handle_sig!(
+        set_mmp,
+        "/v5/account/mmp-modify",
+        POST,
+        Params::Body,
+        SetMmpRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        reset_mmp,
+        "/v5/account/mmp-reset",
+        POST,
+        Params::Body,
+        MmpRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        get_borrow_history,
+        "/v5/account/borrow-history",
+        GET,
+        Params::Query,
+        BorrowHistoryRequest,
+        BorrowHistoryResponse
+    );
+    handle_sig!(
+        get_collateral_info,
+        "/v5/account/collateral-info",
+        GET,
+        Params::Query,
+        CollateralInfoRequest,
+        CollateralInfoResponse
+    );
+    handle_sig!(
+        set_collateral_switch,
+        "/v5/account/set-collateral-switch",
+        POST,
+        Params::Body,
+        CollateralSwitchRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        get_mmp_state,
+        "/v5/account/mmp-state",
+        GET,
+        Params::Query,
+        MmpRequest,
+        MmpStateResponse
+    );
+    handle_sig!(
+        get_transaction_log,
+        "/v5/account/transaction-log",
+        GET,
+        Params::Query,
+        TransactionLogRequest,
+        TransactionLogResponse
+    );
+    handle_sig!(
+        switch_spot_margin_mode,
+        "/v5/spot-margin-trade/switch-mode",
+        POST,
+        Params::Body,
+        SpotMarginModeRequest,
+        SpotMarginModeResponse
+    );
+    handle_sig!(
+        set_spot_margin_leverage,
+        "/v5/spot-margin-trade/set-leverage",
+        POST,
+        Params::Body,
+        SpotMarginLeverageRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        get_spot_margin_state,
+        "/v5/spot-margin-trade/state",
+        GET,
+        SpotMarginStateResponse
+    );
+    handle_sig!(
+        get_spot_margin_data,
+        "/v5/spot-margin-trade/data",
+        GET,
+        Params::Query,
+        SpotMarginDataRequest,
+        SpotMarginDataResponse
+    );
+    handle_sig!(
+        check_spot_borrow,
+        "/v5/order/spot-borrow-check",
+        GET,
+        Params::Query,
+        BorrowCheckRequest,
+        BorrowCheckResponse
+    );
+    handle_sig!(
+        create_internal_transfer,
+        "/v5/asset/transfer/inter-transfer",
+        POST,
+        Params::Body,
+        InternalTransferRequest,
+        InternalTransferResponse
+    );
+    handle_sig!(
+        get_internal_transfer_records,
+        "/v5/asset/transfer/query-inter-transfer-list",
+        GET,
+        Params::Query,
+        InternalTransferRecordsRequest,
+        InternalTransferRecordsResponse
+    );
+    handle_sig!(
+        get_all_coins_balance,
+        "/v5/asset/transfer/query-account-coins-balance",
+        GET,
+        Params::Query,
+        AllCoinsBalanceRequest,
+        AllCoinsBalanceResponse
+    );
+    handle_sig!(
+        get_single_coin_balance,
+        "/v5/asset/transfer/query-account-coin-balance",
+        GET,
+        Params::Query,
+        SingleCoinBalanceRequest,
+        SingleCoinBalanceResponse
+    );
+    handle_sig!(
+        get_transferable_coins,
+        "/v5/asset/transfer/query-transfer-coin-list",
+        GET,
+        Params::Query,
+        TransferableCoinsRequest,
+        TransferableCoinsResponse
+    );
+    handle_sig!(
+        get_deposit_records,
+        "/v5/asset/deposit/query-record",
+        GET,
+        Params::Query,
+        DepositRecordsRequest,
+        DepositRecordsResponse
+    );
+    handle_sig!(
+        get_withdrawal_records,
+        "/v5/asset/withdraw/query-record",
+        GET,
+        Params::Query,
+        WithdrawalRecordsRequest,
+        WithdrawalRecordsResponse
+    );
+    handle_sig!(
+        get_coin_info,
+        "/v5/asset/coin/query-info",
+        GET,
+        Params::Query,
+        CoinInfoRequest,
+        CoinInfoResponse
+    );
+    handle_sig!(
+        get_deposit_address,
+        "/v5/asset/deposit/query-address",
+        GET,
+        Params::Query,
+        DepositAddressRequest,
+        DepositAddressResponse
+    );
+    handle_sig!(
+        withdraw,
+        "/v5/asset/withdraw/create",
+        POST,
+        Params::Body,
+        WithdrawRequest,
+        WithdrawResponse
+    );
+    handle_sig!(
+        cancel_withdrawal,
+        "/v5/asset/withdraw/cancel",
+        POST,
+        Params::Body,
+        CancelWithdrawalRequest,
+        CancelWithdrawalResponse
+    );
+    handle_sig!(
+        create_sub_member,
+        "/v5/user/create-sub-member",
+        POST,
+        Params::Body,
+        CreateSubMemberRequest,
+        SubMember
+    );
+    handle_sig!(
+        get_sub_members,
+        "/v5/user/query-sub-members",
+        GET,
+        SubMembersResponse
+    );
+    handle_sig!(
+        freeze_sub_member,
+        "/v5/user/frozen-sub-member",
+        POST,
+        Params::Body,
+        FreezeSubMemberRequest,
+        EmptyResponse
+    );
+    handle_sig!(
+        create_sub_api_key,
+        "/v5/user/create-sub-api",
+        POST,
+        Params::Body,
+        CreateSubApiKeyRequest,
+        SubApiKeyResponse
+    );
+    handle_sig!(
+        modify_sub_api_key,
+        "/v5/user/update-sub-api",
+        POST,
+        Params::Body,
+        ModifySubApiKeyRequest,
+        SubApiKeyResponse
+    );
+    handle_sig!(
+        delete_sub_api_key,
+        "/v5/user/delete-sub-api",
+        POST,
+        Params::Body,
+        DeleteSubApiKeyRequest,
+        EmptyResponse
+    );
+    handle_sig!(get_api_key_info, "/v5/user/query-api", GET, ApiKeyInfo);
+    handle_sig!(
+        get_convert_coins,
+        "/v5/asset/exchange/query-coin-list",
+        GET,
+        Params::Query,
+        ConvertCoinsRequest,
+        ConvertCoinsResponse
+    );
+    handle_sig!(
+        request_convert_quote,
+        "/v5/asset/exchange/quote-apply",
+        POST,
+        Params::Body,
+        ConvertQuoteRequest,
+        ConvertQuote
+    );
+    handle_sig!(
+        confirm_convert_quote,
+        "/v5/asset/exchange/convert-execute",
+        POST,
+        Params::Body,
+        ConvertExecuteRequest,
+        ConvertExecuteResponse
+    );
+    handle_sig!(
+        get_convert_result,
+        "/v5/asset/exchange/convert-result-query",
+        GET,
+        Params::Query,
+        ConvertResultRequest,
+        ConvertResultResponse
+    );
+    handle!(
+        get_leveraged_token_info,
+        "/v5/spot-lever-token/info",
+        GET,
+        Params::Query,
+        LeveragedTokenRequest,
+        LeveragedTokenInfoResponse
+    );
+    handle!(
+        get_leveraged_token_market,
+        "/v5/spot-lever-token/reference",
+        GET,
+        Params::Query,
+        LeveragedTokenRequest,
+        LeveragedTokenMarket
+    );
+    handle_sig!(
+        purchase_leveraged_token,
+        "/v5/spot-lever-token/purchase",
+        POST,
+        Params::Body,
+        PurchaseLeveragedTokenRequest,
+        PurchaseLeveragedTokenResponse
+    );
+    handle_sig!(
+        redeem_leveraged_token,
+        "/v5/spot-lever-token/redeem",
+        POST,
+        Params::Body,
+        RedeemLeveragedTokenRequest,
+        RedeemLeveragedTokenResponse
+    );
+    handle_sig!(
+        get_leveraged_token_orders,
+        "/v5/spot-lever-token/order-record",
+        GET,
+        Params::Query,
+        LeveragedTokenOrdersRequest,
+        LeveragedTokenOrdersResponse
+    );
+    handle_sig!(
+        get_coin_greeks,
+        "/v5/asset/coin-greeks",
+        GET,
+        Params::Query,
+        CoinGreeksRequest,
+        CoinGreeksResponse
+    );
+    handle_sig!(
+        get_delivery_record,
+        "/v5/asset/delivery-record",
+        GET,
+        Params::Query,
+        DeliveryRecordRequest,
+        DeliveryRecordResponse
+    );
+    handle_sig!(
+        get_settlement_record,
+        "/v5/asset/settlement-record",
+        GET,
+        Params::Query,
+        SettlementRecordRequest,
+        SettlementRecordResponse
+    );
     handle!(
         get_instruments_info,
         "/v5/market/instruments-info",
@@ -126,79 +699,434 @@ impl Client {
         InstrumentsInfoRequest,
         InstrumentsInfoResponse
     );
+    handle!(
+        get_kline,
+        "/v5/market/kline",
+        GET,
+        Params::Query,
+        KlineRequest,
+        KlineResponse
+    );
+    handle!(
+        get_mark_price_kline,
+        "/v5/market/mark-price-kline",
+        GET,
+        Params::Query,
+        KlineRequest,
+        PriceKlineResponse
+    );
+    handle!(
+        get_index_price_kline,
+        "/v5/market/index-price-kline",
+        GET,
+        Params::Query,
+        KlineRequest,
+        PriceKlineResponse
+    );
+    handle!(
+        get_premium_index_price_kline,
+        "/v5/market/premium-index-price-kline",
+        GET,
+        Params::Query,
+        KlineRequest,
+        PriceKlineResponse
+    );
+    handle!(
+        get_orderbook,
+        "/v5/market/orderbook",
+        GET,
+        Params::Query,
+        OrderbookRequest,
+        Orderbook
+    );
+    handle!(
+        get_tickers,
+        "/v5/market/tickers",
+        GET,
+        Params::Query,
+        TickersRequest,
+        TickersResponse
+    );
+    handle!(
+        get_recent_trades,
+        "/v5/market/recent-trade",
+        GET,
+        Params::Query,
+        RecentTradesRequest,
+        RecentTradesResponse
+    );
+    handle!(
+        get_delivery_price,
+        "/v5/market/delivery-price",
+        GET,
+        Params::Query,
+        DeliveryPriceRequest,
+        DeliveryPriceResponse
+    );
+    handle!(
+        get_funding_rate_history,
+        "/v5/market/funding/history",
+        GET,
+        Params::Query,
+        FundingRateHistoryRequest,
+        FundingRateHistoryResponse
+    );
+    handle!(
+        get_open_interest,
+        "/v5/market/open-interest",
+        GET,
+        Params::Query,
+        OpenInterestRequest,
+        OpenInterestResponse
+    );
+    handle!(
+        get_long_short_ratio,
+        "/v5/market/account-ratio",
+        GET,
+        Params::Query,
+        LongShortRatioRequest,
+        LongShortRatioResponse
+    );
+    handle!(
+        get_historical_volatility,
+        "/v5/market/historical-volatility",
+        GET,
+        Params::Query,
+        HistoricalVolatilityRequest,
+        HistoricalVolatilityResponse
+    );
+    handle!(
+        get_insurance,
+        "/v5/market/insurance",
+        GET,
+        Params::Query,
+        InsuranceRequest,
+        InsuranceResponse
+    );
+    handle!(
+        get_risk_limit,
+        "/v5/market/risk-limit",
+        GET,
+        Params::Query,
+        RiskLimitRequest,
+        RiskLimitResponse
+    );
+    handle!(get_server_time, "/v5/market/time", GET, ServerTimeResponse);
+
+    /// Fetches the option chain of `base_coin`, optionally for a single expiry such as `25DEC22`.
+    pub async fn get_option_tickers(
+        &self,
+        base_coin: String,
+        exp_date: Option<String>,
+    ) -> Result<Response<TickersResponse>, Error> {
+        self.get_tickers(TickersRequest {
+            category: Category::Option,
+            symbol: None,
+            base_coin: Some(base_coin),
+            exp_date,
+        })
+        .await
+    }
 
-    async fn request<P: Serialize, R: DeserializeOwned>(
+    /// Requests a convert quote and confirms it while it is still valid.
+    pub async fn convert(
+        &self,
+        request: ConvertQuoteRequest,
+        recv_window: u64,
+    ) -> Result<(ConvertQuote, ConvertExecuteResponse), Error> {
+        let quote = self
+            .request_convert_quote(request, recv_window)
+            .await?
+            .into_result()?;
+
+        let expired_time = quote.expired_millis()?;
+        if self.clock.millis() >= expired_time {
+            return Err(Error::QuoteExpired {
+                quote_tx_id: quote.quote_tx_id,
+                expired_time,
+            });
+        }
+
+        let execution = self
+            .confirm_convert_quote(
+                ConvertExecuteRequest {
+                    quote_tx_id: quote.quote_tx_id.clone(),
+                },
+                recv_window,
+            )
+            .await
+            .and_then(Response::into_result)
+            .map_err(|e| confirm_error(e, &quote, expired_time, self.clock.millis()))?;
+        Ok((quote, execution))
+    }
+
+    async fn request<P: Serialize, T: DeserializeOwned>(
         &self,
         endpoint: String,
         method: Method,
         params: Option<Params<P>>,
-    ) -> Result<R, reqwest::Error> {
+    ) -> Result<Response<T>, Error> {
+        let (params, retry_policy, category) = self.prepare(&endpoint, &method, params);
         let url = format!("{}{}", self.host, endpoint);
 
-        let builder = self.inner.request(method, url);
+        let mut attempt = 0;
+        loop {
+            let group = self.acquire(&endpoint, category.as_deref()).await?;
+            let builder = self.inner.request(method.clone(), &url);
 
-        let request = match params {
-            Some(Params::Body(b)) => {
-                let msg = serde_json::to_string(&b).unwrap();
-                builder.body(msg).build()?
+            let request = match &params {
+                Some(Params::Body(msg)) => builder.body(msg.clone()).build()?,
+                Some(Params::Query(msg)) => {
+                    let mut req = builder.build()?;
+                    req.url_mut().set_query(Some(msg));
+                    req
+                }
+                None => builder.build()?,
+            };
+            let res = self.send(request, group).await;
+            match retry_policy {
+                Some(policy) if policy.should_retry(attempt, &res) => {
+                    tokio::time::sleep(policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                _ => return res,
             }
-            Some(Params::Query(q)) => {
-                let msg = serde_qs::to_string(&q).unwrap();
-                let mut req = builder.build()?;
-                req.url_mut().set_query(Some(&msg));
-                req
-            }
-            None => builder.build()?,
-        };
-        self.inner.execute(request).await?.json::<R>().await
+        }
     }
 
-    async fn request_signed<P: Serialize, R: DeserializeOwned>(
+    async fn request_signed<P: Serialize, T: DeserializeOwned>(
         &self,
         endpoint: String,
         method: Method,
         recv_window: u64,
         params: Option<Params<P>>,
-    ) -> Result<R, reqwest::Error> {
+    ) -> Result<Response<T>, Error> {
+        let (params, retry_policy, category) = self.prepare(&endpoint, &method, params);
         let url = format!("{}{}", self.host, endpoint);
-        let timestamp = millis().to_string();
         let api_key = self.credentials.api_key.clone();
         let recv_window = recv_window.to_string();
 
-        let builder = self
-            .inner
-            .request(method, url)
-            .header("X-BAPI-API-KEY", self.credentials.api_key.clone())
-            .header("X-BAPI-TIMESTAMP", timestamp.clone())
-            .header("X-BAPI-RECV-WINDOW", recv_window.to_string());
-
-        let request = match params {
-            Some(Params::Body(b)) => {
-                let msg = serde_json::to_string(&b).unwrap();
-                let signature = sign(
-                    &self.credentials.secret,
-                    &format!("{}{}{}{}", timestamp, api_key, recv_window, msg),
-                );
-                builder.header("X-BAPI-SIGN", signature).body(msg).build()?
+        let mut attempt = 0;
+        loop {
+            let group = self.acquire(&endpoint, category.as_deref()).await?;
+            // Sign every attempt with a fresh timestamp
+            let timestamp = self.clock.millis().to_string();
+
+            let builder = self
+                .inner
+                .request(method.clone(), &url)
+                .header("X-BAPI-API-KEY", self.credentials.api_key.clone())
+                .header("X-BAPI-TIMESTAMP", timestamp.clone())
+                .header("X-BAPI-RECV-WINDOW", recv_window.to_string());
+
+            let request = match &params {
+                Some(Params::Body(msg)) => {
+                    let signature = sign(
+                        &self.credentials.secret,
+                        &format!("{}{}{}{}", timestamp, api_key, recv_window, msg),
+                    );
+                    builder
+                        .header("X-BAPI-SIGN", signature)
+                        .body(msg.clone())
+                        .build()?
+                }
+                Some(Params::Query(msg)) => {
+                    let signature = sign(
+                        &self.credentials.secret,
+                        &format!("{}{}{}{}", timestamp, api_key, recv_window, msg),
+                    );
+                    let mut req = builder.header("X-BAPI-SIGN", signature).build()?;
+                    req.url_mut().set_query(Some(msg));
+                    req
+                }
+                None => {
+                    let signature = sign(
+                        &self.credentials.secret,
+                        &format!("{}{}{}{}", timestamp, api_key, recv_window, ""),
+                    );
+                    builder.header("X-BAPI-SIGN", signature).build()?
+                }
+            };
+            let res = self.send(request, group).await;
+            match retry_policy {
+                Some(policy) if policy.should_retry(attempt, &res) => {
+                    tokio::time::sleep(policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                _ => return res,
             }
-            Some(Params::Query(q)) => {
-                let msg = serde_qs::to_string(&q).unwrap();
-                let signature = sign(
-                    &self.credentials.secret,
-                    &format!("{}{}{}{}", timestamp, api_key, recv_window, msg),
-                );
-                let mut req = builder.header("X-BAPI-SIGN", signature).build()?;
-                req.url_mut().set_query(Some(&msg));
-                req
+        }
+    }
+
+    /// Encodes the params and looks up the retry policy and rate limit category of the request.
+    fn prepare<P: Serialize>(
+        &self,
+        endpoint: &str,
+        method: &Method,
+        params: Option<Params<P>>,
+    ) -> (Option<Params<String>>, Option<&RetryPolicy>, Option<String>) {
+        let value = match &params {
+            Some(Params::Body(p)) | Some(Params::Query(p))
+                if self.rate_limiter.is_some() || self.retry_policy.is_some() =>
+            {
+                serde_json::to_value(p).ok()
             }
-            None => {
-                let signature = sign(
-                    &self.credentials.secret,
-                    &format!("{}{}{}{}", timestamp, api_key, recv_window, ""),
-                );
-                builder.header("X-BAPI-SIGN", signature).build()?
+            _ => None,
+        };
+        let retry_policy = self
+            .retry_policy
+            .as_ref()
+            .filter(|_| is_idempotent(method, endpoint, value.as_ref()));
+        let category = value
+            .as_ref()
+            .and_then(|v| v.get("category")?.as_str().map(String::from));
+        let params = params.map(|p| match p {
+            Params::Body(b) => Params::Body(serde_json::to_string(&b).unwrap()),
+            Params::Query(q) => Params::Query(serde_qs::to_string(&q).unwrap()),
+        });
+        (params, retry_policy, category)
+    }
+
+    /// Waits for the rate limiter, if any, and returns the limit group of the request.
+    async fn acquire(
+        &self,
+        endpoint: &str,
+        category: Option<&str>,
+    ) -> Result<Option<(String, u64)>, Error> {
+        let rate_limiter = match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter,
+            None => return Ok(None),
+        };
+        let group = limiter::group(endpoint, category);
+        rate_limiter.acquire(group.as_ref()).await?;
+        Ok(group)
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::Request,
+        group: Option<(String, u64)>,
+    ) -> Result<Response<T>, Error> {
+        let res = self.inner.execute(request).await?;
+        let status = res.status();
+        let meta = ResponseMeta::from_headers(res.headers());
+        if let (Some(rate_limiter), Some((group, _))) = (&self.rate_limiter, &group) {
+            rate_limiter.update(group, &meta);
+        }
+        let body = res.text().await?;
+        if !status.is_success() {
+            return Err(Error::Status {
+                status,
+                body,
+                meta: Box::new(meta),
+            });
+        }
+        let mut response = match serde_json::from_str::<Response<T>>(&body) {
+            Ok(response) => response,
+            Err(source) => {
+                return Err(Error::Decode {
+                    source,
+                    body,
+                    meta: Box::new(meta),
+                })
             }
         };
-        self.inner.execute(request).await?.json::<R>().await
+        response.meta = meta;
+        Ok(response)
+    }
+}
+
+/// Sets the offset of `clock` from a server time response, leaving it unchanged on failure.
+fn apply_server_time(
+    clock: &Clock,
+    sent: u64,
+    received: u64,
+    res: Response<ServerTimeResponse>,
+) -> Result<i64, Error> {
+    let server = res.into_result()?.millis()?;
+    let offset = clock_offset(sent, received, server);
+    clock.set_offset(offset);
+    Ok(offset)
+}
+
+/// Reports a rejected confirmation as `Error::QuoteExpired` if the quote expired in flight.
+fn confirm_error(e: Error, quote: &ConvertQuote, expired_time: u64, now: u64) -> Error {
+    match &e {
+        Error::Api { ret_msg, .. }
+            if now >= expired_time || ret_msg.to_lowercase().contains("expire") =>
+        {
+            Error::QuoteExpired {
+                quote_tx_id: quote.quote_tx_id.clone(),
+                expired_time,
+            }
+        }
+        _ => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_time(result: &str) -> Response<ServerTimeResponse> {
+        serde_json::from_str(&format!(
+            r#"{{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{{}},"time":1}}"#,
+            result
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_server_time() {
+        let clock = Clock::new();
+        clock.set_offset(42);
+        for result in [
+            r#"{"timeSecond":"","timeNano":""}"#,
+            r#"{"timeSecond":"0","timeNano":"abc"}"#,
+            "{}",
+        ] {
+            assert!(apply_server_time(&clock, 1_000, 1_000, server_time(result)).is_err());
+            assert_eq!(clock.offset(), 42);
+        }
+        let res: Response<ServerTimeResponse> = serde_json::from_str(
+            r#"{"retCode":10016,"retMsg":"Server error","result":{},"retExtInfo":{},"time":1}"#,
+        )
+        .unwrap();
+        assert!(apply_server_time(&clock, 1_000, 1_000, res).is_err());
+        assert_eq!(clock.offset(), 42);
+
+        // Falls back to `timeSecond`
+        let res = server_time(r#"{"timeSecond":"2","timeNano":"x"}"#);
+        assert_eq!(apply_server_time(&clock, 1_000, 1_000, res).unwrap(), 1_000);
+        let res = server_time(r#"{"timeSecond":"2","timeNano":"3000000000"}"#);
+        assert_eq!(apply_server_time(&clock, 1_000, 1_000, res).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn test_confirm_error() {
+        let quote: ConvertQuote = serde_json::from_str(
+            r#"{"quoteTxId":"q","exchangeRate":"1","fromCoin":"ETH","fromCoinType":"crypto","toCoin":"BTC","toCoinType":"crypto","fromAmount":"1","toAmount":"1","expiredTime":"abc","requestId":""}"#,
+        )
+        .unwrap();
+        assert!(matches!(quote.expired_millis(), Err(Error::Decode { .. })));
+
+        let rejected = |ret_msg: &str| Error::Api {
+            ret_code: 790000,
+            ret_msg: ret_msg.to_string(),
+            ret_ext_info: Default::default(),
+            meta: Default::default(),
+        };
+        match confirm_error(rejected("system error"), &quote, 2_000, 2_000) {
+            Error::QuoteExpired { expired_time, .. } => assert_eq!(expired_time, 2_000),
+            e => panic!("unexpected {:?}", e),
+        }
+        match confirm_error(rejected("Quote expired"), &quote, 2_000, 1_000) {
+            Error::QuoteExpired { quote_tx_id, .. } => assert_eq!(quote_tx_id, "q"),
+            e => panic!("unexpected {:?}", e),
+        }
+        assert!(matches!(
+            confirm_error(rejected("system error"), &quote, 2_000, 1_000),
+            Error::Api { .. }
+        ));
     }
 }
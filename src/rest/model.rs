@@ -1,8 +1,18 @@
 use std::collections::HashMap;
 
+use chrono::{Months, TimeZone, Utc};
+use reqwest::header::HeaderMap;
 use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use crate::util::{millis, uuid};
+use crate::{Error, RetCode};
+
+pub use crate::ws::private::model::{Execution, Greek, Order, Position, Wallet, WalletCoin};
+pub use crate::ws::public::model::{
+    FutureTicker, Kline, OptionTicker, Orderbook, SpotTicker, Trade,
+};
+
 pub fn deserialize_empty_object<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
@@ -33,10 +43,77 @@ pub struct Response<T: DeserializeOwned> {
     pub ret_msg: String,
     #[serde(deserialize_with = "deserialize_empty_object")]
     pub result: Option<T>,
-    pub ret_ext_info: HashMap<String, String>,
+    pub ret_ext_info: RetExtInfo,
     pub time: u64,
+    #[serde(skip)]
+    pub meta: ResponseMeta,
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    pub fn code(&self) -> RetCode {
+        RetCode::from(self.ret_code)
+    }
+
+    /// Turns a nonzero `retCode` into `Error::Api`, otherwise returns the result.
+    pub fn into_result(self) -> Result<T, Error> {
+        if self.ret_code != 0 {
+            return Err(Error::Api {
+                ret_code: self.ret_code,
+                ret_msg: self.ret_msg,
+                ret_ext_info: Box::new(self.ret_ext_info),
+                meta: Box::new(self.meta),
+            });
+        }
+        self.result.ok_or(Error::MissingResult)
+    }
+}
+
+/// The rate limit and trace headers of a response.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseMeta {
+    /// `X-Bapi-Limit`: the request limit of the endpoint.
+    pub limit: Option<u64>,
+    /// `X-Bapi-Limit-Status`: the remaining requests of the current window.
+    pub limit_status: Option<u64>,
+    /// `X-Bapi-Limit-Reset-Timestamp`: when the current window resets (ms).
+    pub limit_reset_timestamp: Option<u64>,
+    /// `Traceid`: the id to quote to Bybit support.
+    pub trace_id: Option<String>,
+    /// `Timenow`: the server time (ms).
+    pub time_now: Option<u64>,
+}
+
+impl ResponseMeta {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let number = |name: &str| header(name).and_then(|v| v.parse().ok());
+        ResponseMeta {
+            limit: number("X-Bapi-Limit"),
+            limit_status: number("X-Bapi-Limit-Status"),
+            limit_reset_timestamp: number("X-Bapi-Limit-Reset-Timestamp"),
+            trace_id: header("Traceid").map(String::from),
+            time_now: number("Timenow"),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RetExtInfo {
+    #[serde(default)]
+    pub list: Vec<RetExtInfoItem>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RetExtInfoItem {
+    pub code: u64,
+    pub msg: String,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EmptyResponse {}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Category {
@@ -46,6 +123,25 @@ pub enum Category {
     Option,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AccountType {
+    Unified,
+    Contract,
+    Spot,
+    Fund,
+    Option,
+    Investment,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DcpProduct {
+    Options,
+    Derivatives,
+    Spot,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub enum Side {
     Buy,
@@ -66,6 +162,20 @@ pub enum PositionIdx {
     Short = 2,
 }
 
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
+#[repr(u8)]
+pub enum TradeMode {
+    Cross = 0,
+    Isolated = 1,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
+#[repr(u8)]
+pub enum PositionMode {
+    MergedSingle = 0,
+    BothSides = 3,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub enum TriggerPrice {
@@ -268,3 +378,2084 @@ pub struct PlaceOrderResponse {
     pub order_id: String,
     pub order_link_id: String,
 }
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub order_id: Option<String>,
+    pub order_link_id: Option<String>,
+    pub order_iv: Option<String>,
+    pub trigger_price: Option<String>,
+    pub qty: Option<String>,
+    pub price: Option<String>,
+    pub tpsl_mode: Option<TpslMode>,
+    pub take_profit: Option<String>,
+    pub stop_loss: Option<String>,
+    pub tp_trigger_by: Option<TriggerPrice>,
+    pub sl_trigger_by: Option<TriggerPrice>,
+    pub trigger_by: Option<TriggerPrice>,
+    pub tp_limit_price: Option<String>,
+    pub sl_limit_price: Option<String>,
+}
+
+impl Default for AmendOrderRequest {
+    fn default() -> Self {
+        AmendOrderRequest {
+            category: Category::Spot,
+            symbol: "".to_string(),
+            order_id: None,
+            order_link_id: None,
+            order_iv: None,
+            trigger_price: None,
+            qty: None,
+            price: None,
+            tpsl_mode: None,
+            take_profit: None,
+            stop_loss: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            trigger_by: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderResponse {
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPlaceOrderRequest {
+    pub category: Category,
+    pub request: Vec<PlaceOrderRequest>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchAmendOrderRequest {
+    pub category: Category,
+    pub request: Vec<AmendOrderRequest>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCancelOrderRequest {
+    pub category: Category,
+    pub request: Vec<CancelOrderRequest>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderResponse {
+    pub list: Vec<BatchOrderItem>,
+}
+
+/// The result of one order of a batch, see `retExtInfo.list` at the same index.
+///
+/// The fields of a failed order are empty strings.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderItem {
+    pub category: String,
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub create_at: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub settle_coin: Option<String>,
+    pub order_id: Option<String>,
+    pub order_link_id: Option<String>,
+    pub open_only: Option<u8>,
+    pub order_filter: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+impl Default for OpenOrdersRequest {
+    fn default() -> Self {
+        OpenOrdersRequest {
+            category: Category::Spot,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            order_id: None,
+            order_link_id: None,
+            open_only: None,
+            order_filter: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderHistoryRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub settle_coin: Option<String>,
+    pub order_id: Option<String>,
+    pub order_link_id: Option<String>,
+    pub order_filter: Option<String>,
+    pub order_status: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+impl Default for OrderHistoryRequest {
+    fn default() -> Self {
+        OrderHistoryRequest {
+            category: Category::Spot,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            order_id: None,
+            order_link_id: None,
+            order_filter: None,
+            order_status: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersResponse {
+    pub category: Category,
+    pub list: Vec<Order>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionsRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub order_id: Option<String>,
+    pub order_link_id: Option<String>,
+    pub base_coin: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub exec_type: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+impl Default for ExecutionsRequest {
+    fn default() -> Self {
+        ExecutionsRequest {
+            category: Category::Spot,
+            symbol: None,
+            order_id: None,
+            order_link_id: None,
+            base_coin: None,
+            start_time: None,
+            end_time: None,
+            exec_type: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionsResponse {
+    pub category: Category,
+    pub list: Vec<Execution>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionsRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub settle_coin: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+impl Default for PositionsRequest {
+    fn default() -> Self {
+        PositionsRequest {
+            category: Category::Linear,
+            symbol: None,
+            base_coin: None,
+            settle_coin: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionsResponse {
+    pub category: Category,
+    pub list: Vec<Position>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverageRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub buy_leverage: String,
+    pub sell_leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchIsolatedRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub trade_mode: TradeMode,
+    pub buy_leverage: String,
+    pub sell_leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchPositionModeRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub coin: Option<String>,
+    pub mode: PositionMode,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTpslModeRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub tp_sl_mode: TpslMode,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTpslModeResponse {
+    pub tp_sl_mode: TpslMode,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingStopRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub tpsl_mode: TpslMode,
+    pub position_idx: PositionIdx,
+    pub take_profit: Option<String>,
+    pub stop_loss: Option<String>,
+    pub trailing_stop: Option<String>,
+    pub tp_trigger_by: Option<TriggerPrice>,
+    pub sl_trigger_by: Option<TriggerPrice>,
+    pub active_price: Option<String>,
+    pub tp_size: Option<String>,
+    pub sl_size: Option<String>,
+    pub tp_limit_price: Option<String>,
+    pub sl_limit_price: Option<String>,
+    pub tp_order_type: Option<OrderType>,
+    pub sl_order_type: Option<OrderType>,
+}
+
+impl Default for TradingStopRequest {
+    fn default() -> Self {
+        TradingStopRequest {
+            category: Category::Linear,
+            symbol: "".to_string(),
+            tpsl_mode: TpslMode::Full,
+            position_idx: PositionIdx::Both,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            active_price: None,
+            tp_size: None,
+            sl_size: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+            tp_order_type: None,
+            sl_order_type: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalanceRequest {
+    pub account_type: AccountType,
+    pub coin: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalanceResponse {
+    pub list: Vec<Wallet>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfoResponse {
+    pub unified_margin_status: u8,
+    pub margin_mode: String,
+    pub is_master_trader: bool,
+    pub spot_hedging_status: String,
+    pub updated_time: String,
+    pub dcp_status: Option<String>,
+    pub time_window: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRateRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRateResponse {
+    pub list: Vec<FeeRate>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRate {
+    pub symbol: String,
+    pub base_coin: String,
+    pub taker_fee_rate: String,
+    pub maker_fee_rate: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineRequest {
+    pub category: Option<Category>,
+    pub symbol: String,
+    pub interval: String,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineResponse {
+    pub category: Category,
+    pub symbol: String,
+    pub list: Vec<KlineItem>,
+}
+
+/// A kline sent as `[startTime, openPrice, highPrice, lowPrice, closePrice, volume, turnover]`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineItem {
+    pub start_time: String,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub close: String,
+    pub volume: String,
+    pub turnover: String,
+}
+
+impl KlineItem {
+    /// Converts to the websocket `Kline` of `interval`, see `PriceKlineItem::into_kline`.
+    pub fn into_kline(self, interval: &str) -> Result<Kline, Error> {
+        let kline = PriceKlineItem {
+            start_time: self.start_time,
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+        }
+        .into_kline(interval)?;
+        Ok(Kline {
+            volume: Some(self.volume),
+            turnover: Some(self.turnover),
+            ..kline
+        })
+    }
+}
+
+impl KlineResponse {
+    /// The klines as websocket `Kline`s of `interval` (as requested), newest first.
+    pub fn into_klines(self, interval: &str) -> Result<Vec<Kline>, Error> {
+        self.list
+            .into_iter()
+            .map(|kline| kline.into_kline(interval))
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceKlineResponse {
+    pub category: Category,
+    pub symbol: String,
+    pub list: Vec<PriceKlineItem>,
+}
+
+/// A price kline sent as `[startTime, openPrice, highPrice, lowPrice, closePrice]`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceKlineItem {
+    pub start_time: String,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub close: String,
+}
+
+impl PriceKlineItem {
+    /// Converts to the websocket `Kline` of `interval` (as requested).
+    ///
+    /// The REST api has no time of the last match, so `timestamp` is the end of
+    /// the kline, or now if the kline is not confirmed yet.
+    pub fn into_kline(self, interval: &str) -> Result<Kline, Error> {
+        let start = self
+            .start_time
+            .parse::<u64>()
+            .map_err(|_| Error::invalid("invalid startTime", &self.start_time))?;
+        let end = kline_end(start, interval)
+            .ok_or_else(|| Error::invalid("invalid interval", interval))?;
+        let now = millis();
+        Ok(Kline {
+            start,
+            end,
+            interval: interval.to_string(),
+            open: self.open,
+            close: self.close,
+            high: self.high,
+            low: self.low,
+            volume: None,
+            turnover: None,
+            confirm: end < now,
+            timestamp: end.min(now),
+        })
+    }
+}
+
+impl PriceKlineResponse {
+    /// The klines as websocket `Kline`s of `interval` (as requested), newest first.
+    pub fn into_klines(self, interval: &str) -> Result<Vec<Kline>, Error> {
+        self.list
+            .into_iter()
+            .map(|kline| kline.into_kline(interval))
+            .collect()
+    }
+}
+
+/// The last millisecond of the kline of `interval` starting at `start`.
+fn kline_end(start: u64, interval: &str) -> Option<u64> {
+    let next = match interval {
+        "D" => start + 86_400_000,
+        "W" => start + 7 * 86_400_000,
+        "M" => Utc
+            .timestamp_millis_opt(start as i64)
+            .single()?
+            .checked_add_months(Months::new(1))?
+            .timestamp_millis() as u64,
+        minutes => start + minutes.parse::<u64>().ok().filter(|m| *m > 0)? * 60_000,
+    };
+    Some(next - 1)
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderbookRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TickersRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub exp_date: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TickersResponse {
+    pub category: Category,
+    pub list: Vec<Ticker>,
+}
+
+impl<'de> Deserialize<'de> for TickersResponse {
+    // The tickers are decoded by `category`, as their fields overlap
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            category: Category,
+            list: Vec<serde_json::Value>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let list = raw
+            .list
+            .into_iter()
+            .map(|ticker| match raw.category {
+                Category::Spot => serde_json::from_value(ticker).map(Ticker::Spot),
+                Category::Option => serde_json::from_value(ticker).map(Ticker::Option),
+                Category::Linear | Category::Inverse => {
+                    serde_json::from_value(ticker).map(Ticker::Future)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)?;
+        Ok(TickersResponse {
+            category: raw.category,
+            list,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Ticker {
+    // Box because large size difference between variants
+    Option(Box<OptionTicker>),
+    Spot(Box<SpotTicker>),
+    Future(Box<FutureTicker>),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTradesRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub option_type: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTradesResponse {
+    pub category: Category,
+    pub list: Vec<RecentTrade>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTrade {
+    pub exec_id: String,
+    pub symbol: String,
+    pub price: String,
+    pub size: String,
+    pub side: Side,
+    pub time: String,
+    pub is_block_trade: bool,
+}
+
+impl TryFrom<RecentTrade> for Trade {
+    type Error = Error;
+
+    fn try_from(trade: RecentTrade) -> Result<Self, Error> {
+        let time = trade
+            .time
+            .parse::<u64>()
+            .map_err(|_| Error::invalid("invalid time", &trade.time))?;
+        Ok(Trade {
+            T: time,
+            s: trade.symbol,
+            S: match trade.side {
+                Side::Buy => crate::ws::public::model::Side::Buy,
+                Side::Sell => crate::ws::public::model::Side::Sell,
+            },
+            v: trade.size,
+            p: trade.price,
+            L: None,
+            i: trade.exec_id,
+            BT: trade.is_block_trade,
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistoryRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistoryResponse {
+    pub category: Category,
+    pub list: Vec<FundingRate>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_rate: String,
+    pub funding_rate_timestamp: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub interval_time: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestResponse {
+    pub category: Category,
+    pub symbol: String,
+    pub list: Vec<OpenInterest>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub open_interest: String,
+    pub timestamp: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub period: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioResponse {
+    pub list: Vec<LongShortRatio>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    pub buy_ratio: String,
+    pub sell_ratio: String,
+    pub timestamp: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalVolatilityRequest {
+    pub category: Category,
+    pub base_coin: Option<String>,
+    pub quote_coin: Option<String>,
+    pub period: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HistoricalVolatilityResponse(pub Vec<HistoricalVolatility>);
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalVolatility {
+    pub period: u64,
+    pub value: String,
+    pub time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InsuranceRequest {
+    pub coin: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InsuranceResponse {
+    pub updated_time: String,
+    pub list: Vec<Insurance>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Insurance {
+    pub coin: String,
+    pub balance: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimitRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimitResponse {
+    pub category: Category,
+    pub list: Vec<RiskLimit>,
+    pub next_page_cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimit {
+    pub id: u64,
+    pub symbol: String,
+    pub risk_limit_value: String,
+    pub maintenance_margin: String,
+    pub initial_margin: String,
+    pub is_lowest_risk: u8,
+    pub max_leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTimeResponse {
+    pub time_second: String,
+    pub time_nano: String,
+}
+
+impl ServerTimeResponse {
+    /// The server time (ms) from `timeNano`, or from `timeSecond` if that is not a timestamp.
+    pub fn millis(&self) -> Result<u64, Error> {
+        let nano = self.time_nano.parse::<u64>().ok().map(|t| t / 1_000_000);
+        let second = || self.time_second.parse::<u64>().ok().map(|t| t * 1_000);
+        nano.filter(|t| *t > 0)
+            .or_else(|| second().filter(|t| *t > 0))
+            .ok_or_else(|| {
+                Error::invalid(
+                    "invalid server time",
+                    &format!(
+                        "timeSecond: {:?}, timeNano: {:?}",
+                        self.time_second, self.time_nano
+                    ),
+                )
+            })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferStatus {
+    Success,
+    Pending,
+    Failed,
+    StatusUnknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransferRequest {
+    pub transfer_id: String,
+    pub coin: String,
+    pub amount: String,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+}
+
+impl InternalTransferRequest {
+    /// Creates a transfer with a fresh `transfer_id`; resend the same request to retry it.
+    pub fn new(
+        coin: String,
+        amount: String,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Self {
+        InternalTransferRequest {
+            transfer_id: uuid(),
+            coin,
+            amount,
+            from_account_type,
+            to_account_type,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransferResponse {
+    pub transfer_id: String,
+    pub status: Option<TransferStatus>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransferRecordsRequest {
+    pub transfer_id: Option<String>,
+    pub coin: Option<String>,
+    pub status: Option<TransferStatus>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransferRecordsResponse {
+    pub list: Vec<InternalTransferRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransferRecord {
+    pub transfer_id: String,
+    pub coin: String,
+    pub amount: String,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+    pub timestamp: String,
+    pub status: TransferStatus,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AllCoinsBalanceRequest {
+    pub account_type: AccountType,
+    pub member_id: Option<String>,
+    pub coin: Option<String>,
+    pub with_bonus: Option<u8>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AllCoinsBalanceResponse {
+    pub account_type: AccountType,
+    pub member_id: Option<String>,
+    pub balance: Vec<CoinBalance>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleCoinBalanceRequest {
+    pub account_type: AccountType,
+    pub coin: String,
+    pub member_id: Option<String>,
+    pub to_member_id: Option<String>,
+    pub to_account_type: Option<AccountType>,
+    pub with_bonus: Option<u8>,
+    pub with_transfer_safe_amount: Option<u8>,
+    pub with_ltv_transfer_safe_amount: Option<u8>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleCoinBalanceResponse {
+    pub account_type: AccountType,
+    pub biz_type: u64,
+    pub account_id: String,
+    pub member_id: String,
+    pub balance: CoinBalance,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinBalance {
+    pub coin: String,
+    pub wallet_balance: String,
+    pub transfer_balance: String,
+    pub bonus: Option<String>,
+    pub transfer_safe_amount: Option<String>,
+    pub ltv_transfer_safe_amount: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferableCoinsRequest {
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferableCoinsResponse {
+    pub list: Vec<String>,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
+#[repr(u16)]
+pub enum DepositStatus {
+    Unknown = 0,
+    ToBeConfirmed = 1,
+    Processing = 2,
+    Success = 3,
+    Failed = 4,
+    PendingToFundingPool = 10011,
+    CreditedToFundingPool = 10012,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub enum WithdrawStatus {
+    SecurityCheck,
+    Pending,
+    #[serde(rename = "success")]
+    Success,
+    CancelByUser,
+    Reject,
+    Fail,
+    BlockchainConfirmed,
+    MoreInformationRequired,
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecordsRequest {
+    pub coin: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecordsResponse {
+    pub rows: Vec<DepositRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub coin: String,
+    pub chain: String,
+    pub amount: String,
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    pub status: DepositStatus,
+    pub to_address: String,
+    pub tag: String,
+    pub deposit_fee: String,
+    pub success_at: String,
+    pub confirmations: String,
+    pub tx_index: String,
+    pub block_hash: String,
+    pub batch_release_limit: String,
+    pub deposit_type: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecordsRequest {
+    #[serde(rename = "withdrawID")]
+    pub withdraw_id: Option<String>,
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    pub coin: Option<String>,
+    pub withdraw_type: Option<u8>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecordsResponse {
+    pub rows: Vec<WithdrawalRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecord {
+    pub withdraw_id: String,
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    pub withdraw_type: u8,
+    pub coin: String,
+    pub chain: String,
+    pub amount: String,
+    pub withdraw_fee: String,
+    pub status: WithdrawStatus,
+    pub to_address: String,
+    pub tag: String,
+    pub create_time: String,
+    pub update_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfoRequest {
+    pub coin: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfoResponse {
+    pub rows: Vec<CoinInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    pub name: String,
+    pub coin: String,
+    pub remain_amount: String,
+    pub chains: Vec<CoinChain>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinChain {
+    pub chain: String,
+    pub chain_type: String,
+    pub confirmation: String,
+    pub withdraw_fee: String,
+    pub deposit_min: String,
+    pub withdraw_min: String,
+    pub min_accuracy: String,
+    pub chain_deposit: String,
+    pub chain_withdraw: String,
+    pub withdraw_percentage_fee: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressRequest {
+    pub coin: String,
+    pub chain_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressResponse {
+    pub coin: String,
+    pub chains: Vec<DepositAddress>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    pub chain_type: String,
+    pub address_deposit: String,
+    pub tag_deposit: String,
+    pub chain: String,
+    pub batch_release_limit: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRequest {
+    pub coin: String,
+    pub chain: Option<String>,
+    pub address: String,
+    pub tag: Option<String>,
+    pub amount: String,
+    /// Current timestamp (ms), e.g. from `Client::clock`.
+    pub timestamp: u64,
+    pub force_chain: Option<u8>,
+    pub account_type: Option<AccountType>,
+    pub fee_type: Option<u8>,
+    pub request_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawResponse {
+    pub id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelWithdrawalRequest {
+    pub id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelWithdrawalResponse {
+    pub status: u8,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnlRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnlResponse {
+    pub category: Category,
+    pub list: Vec<ClosedPnl>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    pub symbol: String,
+    pub order_id: String,
+    pub side: Side,
+    pub qty: String,
+    pub order_price: String,
+    pub order_type: OrderType,
+    pub exec_type: String,
+    pub closed_size: String,
+    pub cum_entry_value: String,
+    pub avg_entry_price: String,
+    pub cum_exit_value: String,
+    pub avg_exit_price: String,
+    pub closed_pnl: String,
+    pub fill_count: String,
+    pub leverage: String,
+    pub created_time: String,
+    pub updated_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLogRequest {
+    pub account_type: Option<AccountType>,
+    pub category: Option<Category>,
+    pub currency: Option<String>,
+    pub base_coin: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLogResponse {
+    pub list: Vec<TransactionLog>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLog {
+    pub id: String,
+    pub symbol: String,
+    pub category: String,
+    pub side: String,
+    pub transaction_time: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub qty: String,
+    pub size: String,
+    pub currency: String,
+    pub trade_price: String,
+    pub funding: String,
+    pub fee: String,
+    pub cash_flow: String,
+    pub change: String,
+    pub cash_balance: String,
+    pub fee_rate: String,
+    pub bonus_change: String,
+    pub trade_id: String,
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubMemberRequest {
+    pub username: String,
+    pub password: Option<String>,
+    pub member_type: u8,
+    pub switch: Option<u8>,
+    pub is_uta: Option<bool>,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMember {
+    pub uid: String,
+    pub username: String,
+    pub member_type: u8,
+    pub status: u8,
+    pub account_mode: Option<u8>,
+    pub remark: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMembersResponse {
+    pub sub_members: Vec<SubMember>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FreezeSubMemberRequest {
+    pub subuid: u64,
+    pub frozen: u8,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApiKeyPermissions {
+    #[serde(default)]
+    pub contract_trade: Vec<String>,
+    #[serde(default)]
+    pub spot: Vec<String>,
+    #[serde(default)]
+    pub wallet: Vec<String>,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub derivatives: Vec<String>,
+    #[serde(default)]
+    pub copy_trading: Vec<String>,
+    #[serde(default)]
+    pub block_trade: Vec<String>,
+    #[serde(default)]
+    pub exchange: Vec<String>,
+    #[serde(default, rename = "NFT")]
+    pub nft: Vec<String>,
+    #[serde(default)]
+    pub affiliate: Vec<String>,
+}
+
+impl ApiKeyPermissions {
+    /// Whether any product can be traded with these permissions.
+    pub fn can_trade(&self) -> bool {
+        self.contract_trade.iter().any(|p| p == "Order")
+            || self.spot.iter().any(|p| p == "SpotTrade")
+            || self.options.iter().any(|p| p == "OptionsTrade")
+            || self.derivatives.iter().any(|p| p == "DerivativesTrade")
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubApiKeyRequest {
+    pub subuid: u64,
+    pub note: Option<String>,
+    pub read_only: u8,
+    pub ips: Option<String>,
+    pub permissions: ApiKeyPermissions,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifySubApiKeyRequest {
+    pub apikey: Option<String>,
+    pub read_only: Option<u8>,
+    pub ips: Option<String>,
+    pub permissions: Option<ApiKeyPermissions>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteSubApiKeyRequest {
+    pub apikey: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubApiKeyResponse {
+    pub id: String,
+    pub note: String,
+    pub api_key: String,
+    pub read_only: u8,
+    pub secret: String,
+    pub permissions: ApiKeyPermissions,
+    #[serde(default)]
+    pub ips: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyInfo {
+    pub id: String,
+    pub note: String,
+    pub api_key: String,
+    pub read_only: u8,
+    pub permissions: ApiKeyPermissions,
+    #[serde(default)]
+    pub ips: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: u8,
+    pub deadline_day: i64,
+    pub expired_at: String,
+    pub created_at: String,
+    pub unified: u8,
+    pub uta: u8,
+    #[serde(rename = "userID")]
+    pub user_id: u64,
+    #[serde(rename = "inviterID")]
+    pub inviter_id: u64,
+    pub vip_level: String,
+    pub mkt_maker_level: String,
+    #[serde(rename = "affiliateID")]
+    pub affiliate_id: u64,
+    pub is_master: bool,
+    pub parent_uid: String,
+}
+
+impl ApiKeyInfo {
+    /// Whether the key is allowed to place orders.
+    pub fn can_trade(&self) -> bool {
+        self.read_only == 0 && self.permissions.can_trade()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDcpRequest {
+    pub product: Option<DcpProduct>,
+    pub time_window: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMmpRequest {
+    pub base_coin: String,
+    pub window: String,
+    pub frozen_period: String,
+    pub qty_limit: String,
+    pub delta_limit: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpRequest {
+    pub base_coin: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpStateResponse {
+    pub result: Vec<MmpState>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpState {
+    pub base_coin: String,
+    pub mmp_enabled: bool,
+    pub window: String,
+    pub frozen_period: String,
+    pub qty_limit: String,
+    pub delta_limit: String,
+    pub mmp_frozen_until: String,
+    pub mmp_frozen: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginModeRequest {
+    /// `1`: on, `0`: off.
+    pub spot_margin_mode: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginModeResponse {
+    pub spot_margin_mode: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginLeverageRequest {
+    pub leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginStateResponse {
+    pub spot_leverage: String,
+    pub spot_margin_mode: String,
+    pub effective_leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginDataRequest {
+    pub vip_level: Option<String>,
+    pub currency: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginDataResponse {
+    pub vip_coin_list: Vec<SpotMarginVipCoins>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginVipCoins {
+    pub vip_level: String,
+    pub list: Vec<SpotMarginCoin>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginCoin {
+    pub currency: String,
+    pub borrowable: bool,
+    pub collateral_ratio: String,
+    pub hourly_borrow_rate: String,
+    pub liquidation_order: String,
+    pub margin_collateral: bool,
+    pub max_borrowing_amount: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowCheckRequest {
+    pub category: Category,
+    pub symbol: String,
+    pub side: Side,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowCheckResponse {
+    pub symbol: String,
+    pub side: Side,
+    pub max_trade_qty: String,
+    pub max_trade_amount: String,
+    pub spot_max_trade_qty: String,
+    pub spot_max_trade_amount: String,
+    pub borrow_coin: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowHistoryRequest {
+    pub currency: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowHistoryResponse {
+    pub list: Vec<BorrowRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRecord {
+    pub currency: String,
+    pub created_time: u64,
+    pub borrow_cost: String,
+    pub hourly_borrow_rate: String,
+    #[serde(rename = "InterestBearingBorrowSize")]
+    pub interest_bearing_borrow_size: String,
+    pub cost_exemption: String,
+    pub borrow_amount: String,
+    pub unrealised_loss: String,
+    pub free_borrowed_amount: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralInfoRequest {
+    pub currency: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralInfoResponse {
+    pub list: Vec<CollateralInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralInfo {
+    pub currency: String,
+    pub hourly_borrow_rate: String,
+    pub max_borrowing_amount: String,
+    pub free_borrowing_limit: String,
+    pub free_borrow_amount: String,
+    pub borrow_amount: String,
+    pub other_borrow_amount: String,
+    pub available_to_borrow: String,
+    pub borrowable: bool,
+    pub borrow_usage_rate: String,
+    pub margin_collateral: bool,
+    pub collateral_switch: bool,
+    pub collateral_ratio: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralSwitchRequest {
+    pub coin: String,
+    /// `ON`, `OFF`.
+    pub collateral_switch: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCoinsRequest {
+    pub account_type: AccountType,
+    pub coin: Option<String>,
+    /// `0`: coins to convert from, `1`: coins to convert to.
+    pub side: Option<u8>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCoinsResponse {
+    pub coins: Vec<ConvertCoin>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertCoin {
+    pub coin: String,
+    pub full_name: String,
+    pub accuracy_length: u64,
+    pub coin_type: String,
+    pub balance: String,
+    pub u_balance: String,
+    pub single_from_min_limit: String,
+    pub single_from_max_limit: String,
+    pub disable_from: bool,
+    pub disable_to: bool,
+    pub time_period: u64,
+    pub single_to_min_limit: String,
+    pub single_to_max_limit: String,
+    pub daily_from_min_limit: String,
+    pub daily_from_max_limit: String,
+    pub daily_to_min_limit: String,
+    pub daily_to_max_limit: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuoteRequest {
+    pub account_type: AccountType,
+    pub from_coin: String,
+    pub to_coin: String,
+    pub from_coin_type: Option<String>,
+    pub to_coin_type: Option<String>,
+    pub request_coin: String,
+    pub request_amount: String,
+    pub param_type: Option<String>,
+    pub param_value: Option<String>,
+    pub request_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    pub quote_tx_id: String,
+    pub exchange_rate: String,
+    pub from_coin: String,
+    pub from_coin_type: String,
+    pub to_coin: String,
+    pub to_coin_type: String,
+    pub from_amount: String,
+    pub to_amount: String,
+    /// The quote is valid until this timestamp (ms).
+    pub expired_time: String,
+    pub request_id: String,
+}
+
+impl ConvertQuote {
+    /// `expired_time` as a timestamp (ms).
+    pub fn expired_millis(&self) -> Result<u64, Error> {
+        self.expired_time
+            .parse::<u64>()
+            .map_err(|_| Error::invalid("invalid expiredTime", &self.expired_time))
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertExecuteRequest {
+    pub quote_tx_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertExecuteResponse {
+    pub quote_tx_id: String,
+    pub exchange_status: ConvertStatus,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum ConvertStatus {
+    Init,
+    Processing,
+    Success,
+    Failure,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertResultRequest {
+    pub quote_tx_id: String,
+    pub account_type: AccountType,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertResultResponse {
+    pub result: ConvertResult,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertResult {
+    pub account_type: AccountType,
+    pub exchange_tx_id: String,
+    pub user_id: String,
+    pub from_coin: String,
+    pub from_coin_type: String,
+    pub to_coin: String,
+    pub to_coin_type: String,
+    pub from_amount: String,
+    pub to_amount: String,
+    pub exchange_status: ConvertStatus,
+    pub convert_rate: String,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenRequest {
+    pub lt_coin: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenInfoResponse {
+    pub list: Vec<LeveragedTokenInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenInfo {
+    pub lt_coin: String,
+    pub lt_name: String,
+    pub max_purchase: String,
+    pub min_purchase: String,
+    pub max_purchase_daily: String,
+    pub max_redeem: String,
+    pub min_redeem: String,
+    pub max_redeem_daily: String,
+    pub purchase_fee_rate: String,
+    pub redeem_fee_rate: String,
+    pub lt_status: String,
+    pub fund_fee: String,
+    pub fund_fee_time: String,
+    pub manage_fee_rate: String,
+    pub manage_fee_time: String,
+    pub value: String,
+    pub net_value: String,
+    pub total: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenMarket {
+    pub lt_coin: String,
+    pub nav: String,
+    pub nav_time: String,
+    pub circulation: String,
+    pub basket: String,
+    pub leverage: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseLeveragedTokenRequest {
+    pub lt_coin: String,
+    pub amount: String,
+    pub serial_no: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseLeveragedTokenResponse {
+    pub lt_coin: String,
+    pub lt_order_status: String,
+    pub exec_qty: String,
+    pub exec_amt: String,
+    pub amount: String,
+    pub purchase_id: String,
+    pub serial_no: String,
+    pub value_coin: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemLeveragedTokenRequest {
+    pub lt_coin: String,
+    pub quantity: String,
+    pub serial_no: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemLeveragedTokenResponse {
+    pub lt_coin: String,
+    pub lt_order_status: String,
+    pub quantity: String,
+    pub exec_qty: String,
+    pub exec_amt: String,
+    pub redeem_id: String,
+    pub serial_no: String,
+    pub value_coin: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenOrdersRequest {
+    pub lt_coin: Option<String>,
+    pub order_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u64>,
+    /// `1`: purchase, `2`: redemption.
+    pub lt_order_type: Option<u8>,
+    pub serial_no: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenOrdersResponse {
+    pub list: Vec<LeveragedTokenOrder>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenOrder {
+    pub lt_coin: String,
+    pub order_id: String,
+    pub lt_order_type: u8,
+    pub order_time: u64,
+    pub update_time: u64,
+    pub lt_order_status: String,
+    pub fee: String,
+    pub amount: String,
+    pub value: String,
+    pub value_coin: String,
+    pub serial_no: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPriceRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub base_coin: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPriceResponse {
+    pub category: Category,
+    pub list: Vec<DeliveryPrice>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPrice {
+    pub symbol: String,
+    pub delivery_price: String,
+    pub delivery_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinGreeksRequest {
+    pub base_coin: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinGreeksResponse {
+    pub list: Vec<Greek>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecordRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub exp_date: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecordResponse {
+    pub category: Category,
+    pub list: Vec<DeliveryRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecord {
+    pub delivery_time: u64,
+    pub symbol: String,
+    pub side: Side,
+    pub position: String,
+    pub delivery_price: String,
+    pub strike: String,
+    pub fee: String,
+    pub delivery_rpl: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecordRequest {
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecordResponse {
+    pub category: Category,
+    pub list: Vec<SettlementRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecord {
+    pub symbol: String,
+    pub side: Side,
+    pub size: String,
+    pub session_avg_price: String,
+    pub mark_price: String,
+    pub realised_pnl: String,
+    pub created_time: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_meta() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Bapi-Limit", "10".parse().unwrap());
+        headers.insert("X-Bapi-Limit-Status", "9".parse().unwrap());
+        headers.insert(
+            "X-Bapi-Limit-Reset-Timestamp",
+            "1672738134824".parse().unwrap(),
+        );
+        headers.insert(
+            "Traceid",
+            "2e2e4cd0ab0a1cc8bb8b8a6a6c4f7a3b".parse().unwrap(),
+        );
+        let meta = ResponseMeta::from_headers(&headers);
+        assert_eq!(meta.limit, Some(10));
+        assert_eq!(meta.limit_status, Some(9));
+        assert_eq!(meta.limit_reset_timestamp, Some(1672738134824));
+        assert_eq!(
+            meta.trace_id.as_deref(),
+            Some("2e2e4cd0ab0a1cc8bb8b8a6a6c4f7a3b")
+        );
+        assert_eq!(meta.time_now, None);
+    }
+
+    #[test]
+    fn test_kline() {
+        let res: Response<KlineResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"category":"inverse","symbol":"BTCUSD","list":[["1670608800000","17071","17073","17027","17055.5","268611","15.74462667"],["1669852800000","17071.5","17071.5","17061","17071","4177","0.24469757"]]},"retExtInfo":{},"time":1672025956592}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        assert_eq!(list[0].close, "17055.5");
+
+        let kline = list[0].clone().into_kline("5").unwrap();
+        assert_eq!(kline.start, 1670608800000);
+        assert_eq!(kline.end, 1670609099999);
+        assert_eq!(kline.interval, "5");
+        assert_eq!(kline.volume.as_deref(), Some("268611"));
+        assert!(kline.confirm);
+        assert_eq!(kline.timestamp, kline.end);
+
+        // 2022-12-01 to 2023-01-01
+        let kline = list[1].clone().into_kline("M").unwrap();
+        assert_eq!(kline.end, 1672531199999);
+        assert!(list[1].clone().into_kline("X").is_err());
+    }
+
+    #[test]
+    fn test_batch_order_response() {
+        let res: Response<BatchOrderResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"category":"linear","symbol":"BTCUSDT","orderId":"b4d0b8c1-5e8f-4f4f-8b1d-0d3c6f1b2a11","orderLinkId":"a","createAt":"1702890040040"},{"category":"","symbol":"","orderId":"","orderLinkId":"","createAt":""}]},"retExtInfo":{"list":[{"code":0,"msg":"OK"},{"code":110007,"msg":"ab not enough for new order"}]},"time":1702890040045}"#,
+        )
+        .unwrap();
+        let info = res.ret_ext_info.clone();
+        let list = res.into_result().unwrap().list;
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].order_link_id, "a");
+        assert_eq!(list[1].order_id, "");
+        assert_eq!(info.list[0].code, 0);
+        assert_eq!(
+            RetCode::from(info.list[1].code),
+            RetCode::InsufficientAvailableBalance
+        );
+    }
+
+    #[test]
+    fn test_executions_response() {
+        use crate::ws::private::model::OrderType;
+
+        let res: Response<ExecutionsResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"nextPageCursor":"132766%3A2%2C132766%3A2","category":"linear","list":[{"symbol":"ETHPERP","orderType":"Market","underlyingPrice":"","orderLinkId":"","side":"Buy","indexPrice":"","orderId":"8c065341-7b52-4ca9-ac2c-37e31ac55c94","stopOrderType":"UNKNOWN","leavesQty":"0","execTime":"1672282722429","feeCurrency":"","isMaker":false,"execFee":"0.071409","feeRate":"0.0006","execId":"e0cbe81d-0f18-5866-9415-cf319b5dab3b","tradeIv":"","blockTradeId":"","markPrice":"1183.54","execPrice":"1190.15","markIv":"","orderQty":"0.1","orderPrice":"1236.9","execValue":"119.015","execType":"Trade","execQty":"0.1","closedSize":"","seq":4688002127},{"symbol":"ETHPERP","orderType":"UNKNOWN","underlyingPrice":"","orderLinkId":"","side":"Sell","indexPrice":"","orderId":"1672300800-ETHPERP-3242","stopOrderType":"UNKNOWN","leavesQty":"0","execTime":"1672300800000","feeCurrency":"","isMaker":false,"execFee":"0.0001775","feeRate":"0.0001","execId":"a8a2c6b7-4b8c-4c36-a1d0-7d2b1e1b8f42","tradeIv":"","blockTradeId":"","markPrice":"1183.5","execPrice":"1183.5","markIv":"","orderQty":"0","orderPrice":"0","execValue":"1.775","execType":"Funding","execQty":"0.1","closedSize":"","seq":4688002128}]},"retExtInfo":{},"time":1672283754510}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        assert!(matches!(list[0].order_type, OrderType::Market));
+        assert!(matches!(list[1].order_type, OrderType::Unknown));
+        assert_eq!(list[1].exec_type, "Funding");
+    }
+
+    fn tickers(result: &str) -> TickersResponse {
+        let res: Response<TickersResponse> = serde_json::from_str(&format!(
+            r#"{{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{{}},"time":1673859087947}}"#,
+            result
+        ))
+        .unwrap();
+        res.into_result().unwrap()
+    }
+
+    #[test]
+    fn test_tickers_response() {
+        let res = tickers(
+            r#"{"category":"linear","list":[{"symbol":"BTCUSDT","lastPrice":"16597.00","indexPrice":"16598.54","markPrice":"16596.00","prevPrice24h":"16464.50","price24hPcnt":"0.008047","highPrice24h":"30912.50","lowPrice24h":"15700.00","prevPrice1h":"16595.50","openInterest":"373504107","openInterestValue":"6198827.47","turnover24h":"2352.94950046","volume24h":"49337318","fundingRate":"-0.001034","nextFundingTime":"1672387200000","predictedDeliveryPrice":"","basisRate":"","deliveryFeeRate":"","deliveryTime":"0","ask1Size":"1","bid1Price":"16596.00","ask1Price":"16597.50","bid1Size":"1"}]}"#,
+        );
+        match &res.list[0] {
+            Ticker::Future(ticker) => assert_eq!(ticker.funding_rate.as_deref(), Some("-0.001034")),
+            ticker => panic!("unexpected {:?}", ticker),
+        }
+
+        // Without `usdIndexPrice`, which must not turn it into a future ticker
+        let res = tickers(
+            r#"{"category":"spot","list":[{"symbol":"BTCUSDT","bid1Price":"20517.96","bid1Size":"2","ask1Price":"20527.77","ask1Size":"1.862172","lastPrice":"20533.13","prevPrice24h":"20393.48","price24hPcnt":"0.0068","highPrice24h":"21128.12","lowPrice24h":"20318.89","turnover24h":"243765620.65899866","volume24h":"11801.27771"}]}"#,
+        );
+        match &res.list[0] {
+            Ticker::Spot(ticker) => assert_eq!(ticker.last_price, "20533.13"),
+            ticker => panic!("unexpected {:?}", ticker),
+        }
+
+        let res = tickers(
+            r#"{"category":"option","list":[{"symbol":"BTC-30DEC22-18000-C","bid1Price":"0","bid1Size":"0","bid1Iv":"0","ask1Price":"435","ask1Size":"0.66","ask1Iv":"5","lastPrice":"435","highPrice24h":"435","lowPrice24h":"165","markPrice":"0.00000009","indexPrice":"16600.55","markIv":"0.7567","underlyingPrice":"16590.42","openInterest":"6.3","turnover24h":"2482.73","volume24h":"0.15","totalVolume":"99","totalTurnover":"1967653","delta":"0.00000001","gamma":"0.00000001","vega":"0.00000004","theta":"-0.00000152","predictedDeliveryPrice":"0","change24h":"86"}]}"#,
+        );
+        match &res.list[0] {
+            Ticker::Option(ticker) => assert_eq!(ticker.ask_iv, "5"),
+            ticker => panic!("unexpected {:?}", ticker),
+        }
+    }
+
+    #[test]
+    fn test_recent_trade() {
+        let res: Response<RecentTradesResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"category":"spot","list":[{"execId":"2100000000007764263","symbol":"BTCUSDT","price":"16618.49","size":"0.00012","side":"Buy","time":"1672052955758","isBlockTrade":false},{"execId":"2100000000007764264","symbol":"BTCUSDT","price":"16618.49","size":"0.00012","side":"Sell","time":"","isBlockTrade":false}]},"retExtInfo":{},"time":1672053054358}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        let trade = Trade::try_from(list[0].clone()).unwrap();
+        assert_eq!(trade.T, 1672052955758);
+        assert!(matches!(
+            Trade::try_from(list[1].clone()),
+            Err(Error::Decode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_positions_response() {
+        let res: Response<PositionsResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"positionIdx":0,"riskId":1,"riskLimitValue":"150","symbol":"BTCUSD","side":"Sell","size":"300","avgPrice":"27464.50441675","positionValue":"0.01092319","tradeMode":0,"positionStatus":"Normal","autoAddMargin":1,"adlRankIndicator":2,"leverage":"10","positionBalance":"0.00139186","markPrice":"28224.50","liqPrice":"","bustPrice":"999999.00","positionMM":"0.0000015","positionIM":"0.00010923","tpslMode":"Full","takeProfit":"0.00","stopLoss":"0.00","trailingStop":"0.00","unrealisedPnl":"-0.00029413","cumRealisedPnl":"0.00013123","seq":5723621632,"isReduceOnly":false,"mmrSysUpdatedTime":"","leverageSysUpdatedTime":"","sessionAvgPrice":"","createdTime":"1676538056258","updatedTime":"1697673600012"}],"nextPageCursor":"","category":"inverse"},"retExtInfo":{},"time":1697684980172}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        assert_eq!(list[0].entry_price, "27464.50441675");
+        assert_eq!(list[0].position_mm, "0.0000015");
+        assert!(list[0].category.is_none());
+    }
+
+    #[test]
+    fn test_orders_response() {
+        let res: Response<OrdersResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"orderId":"fd4300ae-7847-404e-b947-b46980a4d140","orderLinkId":"test-000005","blockTradeId":"","symbol":"ETHUSDT","price":"1600.00","qty":"0.10","side":"Buy","isLeverage":"","positionIdx":1,"orderStatus":"New","cancelType":"UNKNOWN","rejectReason":"EC_NoError","avgPrice":"0","leavesQty":"0.10","leavesValue":"160","cumExecQty":"0.00","cumExecValue":"0","cumExecFee":"0","timeInForce":"GTC","orderType":"Limit","stopOrderType":"UNKNOWN","orderIv":"","triggerPrice":"0.00","takeProfit":"2500.00","stopLoss":"1500.00","tpTriggerBy":"LastPrice","slTriggerBy":"LastPrice","triggerDirection":0,"triggerBy":"UNKNOWN","lastPriceOnCreated":"","reduceOnly":false,"closeOnTrigger":false,"smpType":"None","smpGroup":0,"smpOrderId":"","tpslMode":"Full","tpLimitPrice":"","slLimitPrice":"","placeType":"","createdTime":"1684738540559","updatedTime":"1684738540561"}],"nextPageCursor":"page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26","category":"linear"},"retExtInfo":{},"time":1684765770483}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        assert_eq!(list[0].order_link_id, "test-000005");
+        assert!(list[0].tp_trigger_by.is_some());
+        assert!(list[0].trigger_by.is_none());
+    }
+
+    #[test]
+    fn test_wallet_balance_response() {
+        let res: Response<WalletBalanceResponse> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"totalEquity":"3.31216591","accountIMRate":"0","totalMarginBalance":"3.00326056","totalInitialMargin":"0","accountType":"UNIFIED","totalAvailableBalance":"3.00326056","accountMMRate":"0","totalPerpUPL":"0","totalWalletBalance":"3.00326056","accountLTV":"0","totalMaintenanceMargin":"0","coin":[{"availableToBorrow":"3","bonus":"0","accruedInterest":"0","availableToWithdraw":"0","totalOrderIM":"0","equity":"0","totalPositionMM":"0","usdValue":"0","spotHedgingQty":"0.01592413","unrealisedPnl":"0","collateralSwitch":true,"borrowAmount":"0.0","totalPositionIM":"0","walletBalance":"0","cumRealisedPnl":"0","locked":"0","marginCollateral":true,"coin":"BTC"}]}]},"retExtInfo":{},"time":1690872862481}"#,
+        )
+        .unwrap();
+        let list = res.into_result().unwrap().list;
+        assert_eq!(list[0].account_type, "UNIFIED");
+        assert_eq!(list[0].total_perp_upl, "0");
+        assert_eq!(list[0].coin[0].coin, "BTC");
+        assert_eq!(list[0].coin[0].available_to_borrow, "3");
+    }
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use rand::Rng;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::model::Response;
+use crate::{Error, RetCode};
+
+/// Retry policy for requests which are safe to resend.
+///
+/// It applies to GET requests, and to order placement only when every order
+/// has an `orderLinkId`, so a resent order is rejected as a duplicate instead
+/// of being placed twice. If an order was accepted but its response was lost,
+/// the retry therefore fails with `RetCode::DuplicateOrderLinkId` (110072) or
+/// `RetCode::SpotDuplicateOrderLinkId` (170141) rather than returning the
+/// order; query it by its `orderLinkId` then.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Attempts including the first one.
+    pub max_attempts: u32,
+    /// Backoff before the first retry, doubled for every further retry.
+    pub base_delay: Duration,
+    /// Upper bound of the backoff.
+    pub max_delay: Duration,
+    /// Codes of the responses to retry, by default those which are `RetCode::is_retryable`.
+    pub ret_codes: Vec<RetCode>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            ret_codes: RetCode::ALL
+                .iter()
+                .copied()
+                .filter(RetCode::is_retryable)
+                .collect(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff before retry `attempt` (from 0) with equal jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = delay / 2;
+        half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+    }
+
+    /// Whether to retry after `attempt` (from 0) ended with `result`.
+    pub fn should_retry<T: DeserializeOwned>(
+        &self,
+        attempt: u32,
+        result: &Result<Response<T>, Error>,
+    ) -> bool {
+        if attempt + 1 >= self.max_attempts {
+            return false;
+        }
+        match result {
+            Ok(res) => self.ret_codes.contains(&res.code()),
+            Err(Error::Transport(_)) | Err(Error::Timeout(_)) => true,
+            Err(Error::Status { status, .. }) => status.is_server_error(),
+            Err(e) => e.ret_code().is_some_and(|c| self.ret_codes.contains(&c)),
+        }
+    }
+}
+
+/// Whether resending the request cannot have side effects twice.
+pub fn is_idempotent(method: &reqwest::Method, endpoint: &str, params: Option<&Value>) -> bool {
+    let has_link_id = |order: &Value| {
+        order
+            .get("orderLinkId")
+            .and_then(Value::as_str)
+            .is_some_and(|id| !id.is_empty())
+    };
+    if method == reqwest::Method::GET {
+        return true;
+    }
+    match endpoint {
+        "/v5/order/create" => params.is_some_and(has_link_id),
+        "/v5/order/create-batch" => params
+            .and_then(|p| p.get("request"))
+            .and_then(Value::as_array)
+            .is_some_and(|orders| orders.iter().all(has_link_id)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::model::CancelOrderResponse;
+    use reqwest::Method;
+    use serde_json::json;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+        for attempt in 0..10 {
+            let delay = policy.backoff(attempt);
+            assert!(delay <= policy.max_delay);
+            assert!(delay >= (policy.base_delay * 2u32.pow(attempt)).min(policy.max_delay) / 2);
+        }
+    }
+
+    #[test]
+    fn test_default_ret_codes() {
+        let policy = RetryPolicy::default();
+        for code in RetCode::ALL {
+            assert_eq!(policy.ret_codes.contains(code), code.is_retryable());
+        }
+        assert!(policy.ret_codes.contains(&RetCode::SpotBackendTimeout));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::default();
+        let res: Result<Response<CancelOrderResponse>, Error> = Ok(serde_json::from_str(
+            r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#,
+        )
+        .unwrap());
+        assert!(policy.should_retry(0, &res));
+        assert!(!policy.should_retry(2, &res));
+
+        let res: Result<Response<CancelOrderResponse>, Error> = Err(Error::Status {
+            status: reqwest::StatusCode::BAD_GATEWAY,
+            body: String::new(),
+            meta: Box::default(),
+        });
+        assert!(policy.should_retry(0, &res));
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        let order = json!({"category": "linear", "orderLinkId": "a"});
+        assert!(is_idempotent(&Method::GET, "/v5/order/realtime", None));
+        assert!(is_idempotent(
+            &Method::POST,
+            "/v5/order/create",
+            Some(&order)
+        ));
+        assert!(!is_idempotent(
+            &Method::POST,
+            "/v5/order/create",
+            Some(&json!({"category": "linear", "orderLinkId": null}))
+        ));
+        assert!(!is_idempotent(
+            &Method::POST,
+            "/v5/order/cancel",
+            Some(&order)
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+macro_rules! ret_codes {
+    ($($(#[$doc:meta])* $name:ident = $code:literal,)*) => {
+        /// The V5 `retCode`s of the common, derivatives and spot trade, spot margin,
+        /// unified account, asset, leveraged token and convert errors.
+        ///
+        /// Other codes, e.g. of option specific rejections, decode as `Unknown`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum RetCode {
+            $($(#[$doc])* $name,)*
+            Unknown(u64),
+        }
+
+        impl From<u64> for RetCode {
+            fn from(code: u64) -> Self {
+                match code {
+                    $($code => RetCode::$name,)*
+                    _ => RetCode::Unknown(code),
+                }
+            }
+        }
+
+        impl RetCode {
+            /// Every known code, i.e. all but `Unknown`.
+            pub const ALL: &'static [RetCode] = &[$(RetCode::$name,)*];
+
+            pub fn code(&self) -> u64 {
+                match self {
+                    $(RetCode::$name => $code,)*
+                    RetCode::Unknown(code) => *code,
+                }
+            }
+        }
+    };
+}
+
+ret_codes! {
+    Ok = 0,
+    /// Request timeout on the server side.
+    ServerTimeout = 10000,
+    ParamsError = 10001,
+    /// The request time exceeds the time window range (see `recv_window`).
+    InvalidTimestamp = 10002,
+    InvalidApiKey = 10003,
+    InvalidSignature = 10004,
+    PermissionDenied = 10005,
+    /// Too many visits, the UID rate limit is exceeded.
+    TooManyVisits = 10006,
+    UserAuthenticationFailed = 10007,
+    CommonBanned = 10008,
+    IpBanned = 10009,
+    UnmatchedIp = 10010,
+    DuplicateRequest = 10014,
+    /// Internal server error or service restarting.
+    ServerError = 10016,
+    RouteNotFound = 10017,
+    /// The IP rate limit is exceeded.
+    IpRateLimitExceeded = 10018,
+    ComplianceRules = 10024,
+    TransactionsBanned = 10027,
+    SymbolNotWhitelisted = 10029,
+    /// System level frequency protection.
+    SystemFrequencyProtection = 10429,
+    /// The api key has expired.
+    ApiKeyExpired = 33004,
+    OrderNotExists = 110001,
+    OrderPriceOutOfRange = 110003,
+    WalletBalanceInsufficient = 110004,
+    PositionStatus = 110005,
+    AssetsInsufficient = 110006,
+    InsufficientAvailableBalance = 110007,
+    OrderFinished = 110008,
+    StopOrdersExceeded = 110009,
+    OrderAlreadyCancelled = 110010,
+    LiquidationWillTrigger = 110011,
+    InsufficientAvailableBalanceForOrder = 110012,
+    LeverageRiskLimit = 110013,
+    InsufficientBalanceToAddMargin = 110014,
+    PositionInCrossMargin = 110015,
+    RiskLimitQtyExceeded = 110016,
+    ReduceOnlyNotSatisfied = 110017,
+    UserIdIllegal = 110018,
+    OrderIdIllegal = 110019,
+    ActiveOrdersExceeded = 110020,
+    OpenInterestExceeded = 110021,
+    QtyModificationBanned = 110022,
+    OnlyClosePosition = 110023,
+    PositionExistsForModeSwitch = 110024,
+    PositionModeNotModified = 110025,
+    MarginModeNotModified = 110026,
+    MarginNotModified = 110027,
+    OpenOrdersExistForModeSwitch = 110028,
+    HedgeModeNotAvailable = 110029,
+    DuplicateOrderId = 110030,
+    RiskLimitNotExists = 110031,
+    IllegalOrder = 110032,
+    LeverageNotModified = 110043,
+    InsufficientMarginForRiskLimit = 110044,
+    InsufficientBalanceForMarketOrder = 110051,
+    SettlementInProgress = 110063,
+    DuplicateOrderLinkId = 110072,
+    // Spot trade
+    SpotInternalError = 170001,
+    SpotTooManyNewOrders = 170005,
+    /// Timeout waiting for a response from the backend server.
+    SpotBackendTimeout = 170007,
+    SpotInvalidSymbol = 170121,
+    SpotInvalidParam = 170130,
+    SpotInsufficientBalance = 170131,
+    SpotOrderPriceTooHigh = 170132,
+    SpotOrderPriceTooLow = 170133,
+    SpotOrderPriceDecimalTooLong = 170134,
+    SpotOrderQtyTooLarge = 170135,
+    SpotOrderQtyTooSmall = 170136,
+    SpotOrderQtyDecimalTooLong = 170137,
+    SpotOrderFilled = 170139,
+    SpotOrderValueTooSmall = 170140,
+    SpotDuplicateOrderLinkId = 170141,
+    SpotOrderCancelled = 170142,
+    SpotOrderNotOnBook = 170143,
+    SpotOrderLocked = 170144,
+    SpotCancelNotSupported = 170145,
+    SpotOrderCreationTimeout = 170146,
+    SpotOrderCancellationTimeout = 170147,
+    SpotCreateOrderFailed = 170149,
+    SpotCancelOrderFailed = 170150,
+    SpotSymbolNotOpen = 170151,
+    SpotSymbolNotApiTradable = 170157,
+    SpotOrderNotExists = 170213,
+    // Spot margin trade
+    /// Positions are being closed compulsorily, no repayment allowed.
+    SpotMarginCompulsoryClosing = 182100,
+    SpotMarginRepayInsufficientCollateral = 182101,
+    SpotMarginNoLiability = 182102,
+    SpotMarginInstitutionalLoan = 182103,
+    SpotMarginLevelTooLow = 182108,
+    SpotMarginSwitchFailed = 182110,
+    // Asset
+    AssetServiceError = 131001,
+    AssetParamsError = 131002,
+    AssetInternalError = 131003,
+    KycNeeded = 131004,
+    WithdrawUtaUpgrading = 131084,
+    WithdrawInsufficientBalance = 131085,
+    WithdrawRiskLimitExceeded = 131086,
+    WithdrawLimitExceeded = 131088,
+    /// Withdrawals are locked for 24 hours after a sensitive operation.
+    WithdrawSensitiveOperation = 131089,
+    WithdrawBanned = 131090,
+    WithdrawLoginBlocked = 131091,
+    WithdrawUserAbnormal = 131092,
+    WithdrawAddressNotWhitelisted = 131093,
+    WithdrawUserNotWhitelisted = 131094,
+    WithdrawPlatformLimitExceeded = 131095,
+    WithdrawAmountOutOfRange = 131096,
+    WithdrawCoinClosed = 131097,
+    WithdrawNewAddressUnavailable = 131098,
+    WithdrawNotCancellable = 131099,
+    TransferServiceError = 131200,
+    TransferInternalError = 131201,
+    TransferInvalidMemberId = 131202,
+    TransferParamsError = 131203,
+    TransferAccountInfoError = 131204,
+    TransferQueryError = 131205,
+    TransferNotAllowed = 131206,
+    TransferAccountNotExists = 131207,
+    TransferAmountPrecision = 131210,
+    TransferSameAccountType = 131211,
+    TransferInsufficientBalance = 131212,
+    TransferLtvCheckError = 131213,
+    /// The `transferId` was used before.
+    DuplicateTransferId = 131214,
+    TransferAmountError = 131215,
+    TransferRiskCheckError = 131217,
+    UniversalTransferNotPermitted = 131227,
+    TransferSystemBusy = 131230,
+    // Leveraged token
+    LeveragedTokenDuplicateSerialNum = 175000,
+    LeveragedTokenPurchaseLimitExceeded = 175001,
+    /// Too many purchase orders, try again later.
+    LeveragedTokenPurchaseBusy = 175002,
+    LeveragedTokenInsufficientBalance = 175003,
+    LeveragedTokenRedemptionLimitExceeded = 175004,
+    /// Too many redemption orders, try again later.
+    LeveragedTokenRedemptionBusy = 175005,
+    LeveragedTokenOrderNotExists = 175007,
+    LeveragedTokenPurchaseNotStarted = 175008,
+    LeveragedTokenPurchaseAmountExceeded = 175009,
+    LeveragedTokenQuizNotPassed = 175010,
+    LeveragedTokenRedemptionNotStarted = 175012,
+    LeveragedTokenRedemptionAmountExceeded = 175013,
+    LeveragedTokenPurchaseSuspended = 175014,
+    LeveragedTokenRedemptionSuspended = 175015,
+    LeveragedTokenOrderFailed = 175017,
+    // Unified account
+    SetMarginModeFailed = 3400001,
+    /// Hedge mode or isolated margin USDT perpetual positions are still open.
+    UnclosedUsdtPerpetualPositions = 3400004,
+    /// Hedge mode or isolated margin USDC futures positions are still open.
+    UnclosedUsdcFuturesPositions = 3400005,
+    /// Server error, try again later.
+    UnifiedAccountServerError = 3400214,
+    // Convert
+    ConvertParamsError = 700000,
+    /// No dealer could quote the conversion.
+    ConvertNoDealer = 700001,
+    ConvertOrderNotExists = 700004,
+    ConvertLargeAmountLimit = 700007,
+    ConvertUtaUpgrading = 700012,
+    /// System error, try again later.
+    ConvertSystemError = 790000,
+}
+
+impl RetCode {
+    pub fn is_ok(&self) -> bool {
+        *self == RetCode::Ok
+    }
+
+    pub fn is_rate_limit(&self) -> bool {
+        matches!(
+            self,
+            RetCode::TooManyVisits
+                | RetCode::IpRateLimitExceeded
+                | RetCode::SystemFrequencyProtection
+        )
+    }
+
+    /// Whether resending the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.is_rate_limit()
+            || matches!(
+                self,
+                RetCode::ServerTimeout
+                    | RetCode::ServerError
+                    | RetCode::SpotBackendTimeout
+                    | RetCode::TransferSystemBusy
+                    | RetCode::UnifiedAccountServerError
+                    | RetCode::ConvertSystemError
+                    | RetCode::LeveragedTokenPurchaseBusy
+                    | RetCode::LeveragedTokenRedemptionBusy
+            )
+    }
+
+    /// Whether the request failed on the api key, signature or timestamp.
+    pub fn is_auth(&self) -> bool {
+        matches!(
+            self,
+            RetCode::InvalidTimestamp
+                | RetCode::InvalidApiKey
+                | RetCode::ApiKeyExpired
+                | RetCode::InvalidSignature
+                | RetCode::PermissionDenied
+                | RetCode::UserAuthenticationFailed
+                | RetCode::UnmatchedIp
+        )
+    }
+
+    /// Whether no further request with these credentials can succeed.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            RetCode::InvalidApiKey
+                | RetCode::ApiKeyExpired
+                | RetCode::PermissionDenied
+                | RetCode::UserAuthenticationFailed
+                | RetCode::CommonBanned
+                | RetCode::IpBanned
+                | RetCode::UnmatchedIp
+                | RetCode::ComplianceRules
+                | RetCode::TransactionsBanned
+        )
+    }
+
+    pub fn is_order_not_found(&self) -> bool {
+        matches!(self, RetCode::OrderNotExists | RetCode::SpotOrderNotExists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ret_code() {
+        assert_eq!(RetCode::from(0), RetCode::Ok);
+        assert_eq!(RetCode::from(10006), RetCode::TooManyVisits);
+        assert_eq!(RetCode::from(42), RetCode::Unknown(42));
+        assert_eq!(RetCode::InsufficientAvailableBalance.code(), 110007);
+        assert_eq!(RetCode::Unknown(42).code(), 42);
+    }
+
+    #[test]
+    fn test_classification() {
+        assert!(RetCode::from(10006).is_rate_limit());
+        assert!(RetCode::from(10016).is_retryable());
+        assert!(!RetCode::from(110007).is_retryable());
+        assert!(RetCode::from(10002).is_auth());
+        assert!(RetCode::from(110001).is_order_not_found());
+        assert!(!RetCode::from(42).is_fatal());
+        assert!(RetCode::from(33004).is_auth());
+        assert!(RetCode::from(33004).is_fatal());
+        assert!(RetCode::from(175002).is_retryable());
+        assert_eq!(RetCode::from(131212), RetCode::TransferInsufficientBalance);
+        assert_eq!(RetCode::from(790000), RetCode::ConvertSystemError);
+        assert_eq!(RetCode::UnclosedUsdtPerpetualPositions.code(), 3400004);
+    }
+}
@@ -1,11 +1,64 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
 use chrono::Utc;
 use hex;
+use rand::Rng;
 use ring::hmac;
 
 pub fn millis() -> u64 {
     Utc::now().timestamp_millis() as u64
 }
 
+/// Local clock corrected by an offset (ms) to the exchange clock.
+///
+/// Clones share the same offset, so one clock can be handed to several clients.
+#[derive(Clone, Default, Debug)]
+pub struct Clock {
+    offset: Arc<AtomicI64>,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed)
+    }
+
+    pub fn millis(&self) -> u64 {
+        (millis() as i64 + self.offset()) as u64
+    }
+}
+
+/// Offset of the server clock against the local one, assuming the server time
+/// was taken halfway between sending the request and receiving the response.
+pub fn clock_offset(sent: u64, received: u64, server: u64) -> i64 {
+    let local = sent + received.saturating_sub(sent) / 2;
+    server as i64 - local as i64
+}
+
+/// Random (version 4) UUID, e.g. for idempotent transfer ids.
+pub fn uuid() -> String {
+    let mut bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
 pub fn sign(secret: &str, msg: &str) -> String {
     let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
     let tag = hmac::sign(&key, msg.as_bytes());
@@ -21,6 +74,31 @@ mod tests {
         assert!(millis() > 0);
     }
 
+    #[test]
+    fn test_clock_offset() {
+        assert_eq!(clock_offset(1_000, 1_100, 1_050), 0);
+        assert_eq!(clock_offset(1_000, 1_100, 3_050), 2_000);
+        assert_eq!(clock_offset(1_000, 1_100, 50), -1_000);
+    }
+
+    #[test]
+    fn test_clock() {
+        let clock = Clock::new();
+        let shared = clock.clone();
+        shared.set_offset(-5_000);
+        assert_eq!(clock.offset(), -5_000);
+        assert!(clock.millis() < millis());
+    }
+
+    #[test]
+    fn test_uuid() {
+        let id = uuid();
+        assert_eq!(id.len(), 36);
+        assert_eq!(&id[14..15], "4");
+        assert!(["8", "9", "a", "b"].contains(&&id[19..20]));
+        assert_ne!(id, uuid());
+    }
+
     #[test]
     fn test_sign() {
         assert_eq!(
@@ -12,11 +12,13 @@ use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::error::Result;
 use tokio_tungstenite::tungstenite::protocol::Message;
 
-use crate::util::{millis, sign};
+use crate::util::{sign, Clock};
 use crate::Credentials;
 
-fn auth_req(credentials: &Credentials) -> String {
-    let expires = millis() + 10000;
+const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+fn auth_req(credentials: &Credentials, clock: &Clock) -> String {
+    let expires = clock.millis() + 10000;
     let val = format!("GET/realtime{}", expires);
     let signature = sign(&credentials.secret, &val);
     let auth_req = model::Op {
@@ -30,6 +32,8 @@ fn auth_req(credentials: &Credentials) -> String {
 pub struct Client {
     credentials: Credentials,
     url: &'static str,
+    clock: Clock,
+    ping_interval: Duration,
 }
 
 impl Client {
@@ -37,6 +41,8 @@ impl Client {
         Client {
             credentials,
             url: MAINNET_URL,
+            clock: Clock::new(),
+            ping_interval: PING_INTERVAL,
         }
     }
 
@@ -44,9 +50,29 @@ impl Client {
         Client {
             credentials,
             url: TESTNET_URL,
+            clock: Clock::new(),
+            ping_interval: PING_INTERVAL,
         }
     }
 
+    /// Uses `clock` for the auth expiry, e.g. the one synced by `rest::Client::sync_clock`.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Pings every `ping_interval` instead of every 20s.
+    pub fn with_ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    /// Pings often enough to keep a DCP `time_window` (s, see `SetDcpRequest`) alive.
+    pub fn with_dcp_time_window(self, time_window: u64) -> Self {
+        let ping_interval = Duration::from_secs(time_window).div_f64(3.0);
+        self.with_ping_interval(ping_interval.clamp(Duration::from_secs(1), PING_INTERVAL))
+    }
+
     pub async fn connect(
         &self,
     ) -> Result<(
@@ -58,7 +84,7 @@ impl Client {
 
         let (mut sender, mut receiver) = connect_async(self.url).await?.0.split();
         sender
-            .send(Message::Text(auth_req(&self.credentials)))
+            .send(Message::Text(auth_req(&self.credentials, &self.clock)))
             .await?;
 
         tokio::spawn(async move {
@@ -96,6 +122,7 @@ impl Client {
         });
 
         let op_sender_c = op_sender.clone();
+        let ping_interval = self.ping_interval;
         tokio::spawn(async move {
             loop {
                 if op_sender_c
@@ -108,10 +135,30 @@ impl Client {
                 {
                     break;
                 }
-                tokio::time::sleep(Duration::from_secs(20)).await;
+                tokio::time::sleep(ping_interval).await;
             }
         });
 
         Ok((op_sender, res_receiver))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dcp_time_window() {
+        let client = Client::new(Credentials {
+            api_key: String::new(),
+            secret: String::new(),
+        });
+        assert_eq!(client.ping_interval, PING_INTERVAL);
+        let client = client.with_dcp_time_window(10);
+        assert!(client.ping_interval < Duration::from_secs(10) / 2);
+        let client = client.with_dcp_time_window(3);
+        assert_eq!(client.ping_interval, Duration::from_secs(1));
+        let client = client.with_dcp_time_window(300);
+        assert_eq!(client.ping_interval, PING_INTERVAL);
+    }
+}
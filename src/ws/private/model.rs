@@ -2,6 +2,7 @@ use serde::de::IntoDeserializer;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+/// Decodes `""` and `"UNKNOWN"`, which the REST api sends for unset values, as `None`.
 fn empty_string_is_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -10,7 +11,7 @@ where
     let opt = Option::<String>::deserialize(de)?;
     let opt = opt.as_deref();
     match opt {
-        None | Some("") => Ok(None),
+        None | Some("") | Some("UNKNOWN") => Ok(None),
         Some(s) => T::deserialize(s.into_deserializer()).map(Some),
     }
 }
@@ -54,6 +55,9 @@ pub enum TimeInForce {
 pub enum OrderType {
     Market,
     Limit,
+    /// Executions without an order, e.g. funding, delivery or settlement.
+    #[serde(rename = "UNKNOWN")]
+    Unknown,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -69,11 +73,21 @@ pub enum TriggerPrice {
 pub struct OpResponse {
     pub success: bool,
     pub ret_msg: String,
+    /// Only sent with some ops; the auth and subscribe responses carry `ret_msg` only.
+    #[serde(default, alias = "retCode")]
+    pub ret_code: Option<u64>,
     pub conn_id: String,
     pub req_id: Option<String>,
     pub op: String,
 }
 
+impl OpResponse {
+    /// The `retCode` of the op, if the server sent one.
+    pub fn code(&self) -> Option<crate::RetCode> {
+        self.ret_code.map(crate::RetCode::from)
+    }
+}
+
 /// The pong response of private channels.
 #[derive(Deserialize, Debug, Clone)]
 pub struct PongResponse {
@@ -127,7 +141,8 @@ pub struct Position {
     /// Risk limit value corresponding to riskId.
     /// _Note_: for portfolio margin mode, it returns "", which the risk limit value is invalid.
     pub risk_limit_value: String,
-    /// Entry price.
+    /// Entry price. Returned as `avgPrice` by the REST position list.
+    #[serde(alias = "avgPrice")]
     pub entry_price: String,
     /// Mark price
     pub mark_price: String,
@@ -182,12 +197,18 @@ pub struct Execution {
     /// Product type.
     /// - Unified account: `spot`, `linear`, `option`.
     /// - Normal account: `linear`, `inverse`.
+    ///
+    /// Empty for executions queried over REST, where the category is returned once per page.
+    #[serde(default)]
     pub category: String,
     /// Symbol name.
     pub symbol: String,
     /// Whether to borrow. Valid for `spot` only.
     /// - 0 (default): false.
     /// - 1: true.
+    ///
+    /// Empty for executions queried over REST.
+    #[serde(default)]
     pub is_leverage: String,
     /// Order ID.
     pub order_id: String,
@@ -201,7 +222,7 @@ pub struct Execution {
     pub order_qty: String,
     /// The remaining qty not executed.
     pub leaves_qty: String,
-    /// Order type. `Market`, `Limit`.
+    /// Order type. `Market`, `Limit`, `UNKNOWN` for executions without an order.
     pub order_type: OrderType,
     /// Stop order type. If the order is not stop order, any type is not returned.
     pub stop_order_type: String,
@@ -244,6 +265,9 @@ pub struct Order {
     /// Product type.
     /// - Unified account: `spot`, `linear`, `option`.
     /// - Normal account: `linear`, `inverse`.
+    ///
+    /// Empty for orders queried over REST, where the category is returned once per page.
+    #[serde(default)]
     pub category: String,
     /// Order ID.
     pub order_id: String,
@@ -411,6 +435,33 @@ pub struct Greek {
     pub total_theta: String,
 }
 
+/// The disconnect-cancel-all (DCP) data.
+///
+/// Subscribe to `dcp` to arm DCP for this connection. The connection must ping
+/// more often than the time window, see `Client::with_dcp_time_window`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Dcp {
+    /// Product. `OPTIONS`, `DERIVATIVES`, `SPOT`.
+    pub product: String,
+    /// DCP status. `ON`: armed, `OFF`: triggered, the orders were cancelled.
+    pub dcp_status: String,
+    /// Disconnection timing window (s).
+    pub time_window: u64,
+}
+
+/// The market maker protection (MMP) event data.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MmpEvent {
+    /// Base coin.
+    pub base_coin: String,
+    /// Account is frozen until this timestamp (ms).
+    pub mmp_frozen_until: String,
+    /// Whether the account is frozen by MMP.
+    pub mmp_frozen: bool,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Response {
@@ -419,6 +470,8 @@ pub enum Response {
     Order(BaseResponse<Vec<Order>>),
     Wallet(BaseResponse<Vec<Wallet>>),
     Greek(BaseResponse<Vec<Greek>>),
+    Dcp(BaseResponse<Vec<Dcp>>),
+    Mmp(BaseResponse<Vec<MmpEvent>>),
     Pong(PongResponse),
     Op(OpResponse),
 }
@@ -429,3 +482,52 @@ pub struct Op {
     pub op: String,
     pub args: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dcp() {
+        let res: Response = serde_json::from_str(
+            r#"{"id":"10063fd3-5ed8-4c22-9a04-3c0a9f2e6c6d","topic":"dcp","creationTime":1702890040040,"data":[{"product":"OPTIONS","dcpStatus":"ON","timeWindow":10}]}"#,
+        )
+        .unwrap();
+        match res {
+            Response::Dcp(res) => {
+                assert_eq!(res.data[0].dcp_status, "ON");
+                assert_eq!(res.data[0].time_window, 10);
+            }
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_mmp() {
+        let res: Response = serde_json::from_str(
+            r#"{"id":"1eb4bd8d2d46ee7cd3e0a9cb57d25a3c","topic":"mmp.event","creationTime":1699341826479,"data":[{"baseCoin":"ETH","mmpFrozenUntil":"1699341836471","mmpFrozen":true}]}"#,
+        )
+        .unwrap();
+        match res {
+            Response::Mmp(res) => {
+                assert_eq!(res.data[0].base_coin, "ETH");
+                assert!(res.data[0].mmp_frozen);
+            }
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_op_ret_code() {
+        let res: OpResponse = serde_json::from_str(
+            r#"{"success":false,"ret_msg":"Params Error","op":"auth","conn_id":"cejreaspqfh3sjdnldmg-p"}"#,
+        )
+        .unwrap();
+        assert_eq!(res.code(), None);
+        let res: OpResponse = serde_json::from_str(
+            r#"{"success":false,"ret_msg":"Too many visits!","retCode":10006,"op":"order.create","conn_id":"cejreaspqfh3sjdnldmg-p"}"#,
+        )
+        .unwrap();
+        assert!(res.code().unwrap().is_rate_limit());
+    }
+}
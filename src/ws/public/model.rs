@@ -11,11 +11,21 @@ pub enum Side {
 pub struct OpResponse {
     pub success: bool,
     pub ret_msg: String,
+    /// Only sent with some ops; the auth and subscribe responses carry `ret_msg` only.
+    #[serde(default, alias = "retCode")]
+    pub ret_code: Option<u64>,
     pub conn_id: String,
     pub req_id: Option<String>,
     pub op: String,
 }
 
+impl OpResponse {
+    /// The `retCode` of the op, if the server sent one.
+    pub fn code(&self) -> Option<crate::RetCode> {
+        self.ret_code.map(crate::RetCode::from)
+    }
+}
+
 /// The option pong response of public channels.
 #[derive(Deserialize, Debug, Clone)]
 pub struct OptionPongResponse {
@@ -149,27 +159,36 @@ pub struct SpotTicker {
     pub turnover_24h: String,
     /// Percentage change of market price relative to 24h.
     pub price_24h_pcnt: String,
-    /// USD index price. It can be empty.
+    /// USD index price. It can be empty or missing.
+    #[serde(default)]
     pub usd_index_price: String,
 }
 
 /// The option ticker data. (`snapshot` only)
+///
+/// Also decodes the REST tickers, which name the best bid/ask fields `bid1Price`, `ask1Iv`, etc.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionTicker {
     /// Symbol name.
     pub symbol: String,
     /// Best bid price.
+    #[serde(alias = "bid1Price")]
     pub bid_price: String,
     /// Best bid size.
+    #[serde(alias = "bid1Size")]
     pub bid_size: String,
     /// Best bid iv.
+    #[serde(alias = "bid1Iv")]
     pub bid_iv: String,
     /// Best ask price.
+    #[serde(alias = "ask1Price")]
     pub ask_price: String,
     /// Best ask size.
+    #[serde(alias = "ask1Size")]
     pub ask_size: String,
     /// Best ask iv.
+    #[serde(alias = "ask1Iv")]
     pub ask_iv: String,
     /// Last price.
     pub last_price: String,
@@ -182,6 +201,7 @@ pub struct OptionTicker {
     /// Index price.
     pub index_price: String,
     /// Mark price iv.
+    #[serde(alias = "markIv")]
     pub mark_price_iv: String,
     /// Underlying price.
     pub underlying_price: String,